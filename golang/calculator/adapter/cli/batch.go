@@ -0,0 +1,146 @@
+package cli
+
+import (
+	"context"
+	"encoding/csv"
+	"encoding/json"
+	"fmt"
+	"io"
+	"sort"
+	"strconv"
+	"strings"
+)
+
+// Formats of the batch output, see Batch
+var formats = map[string]func(out io.Writer) batchWriter{
+	"plain": func(out io.Writer) batchWriter { return &plainWriter{out: out} },
+	"csv":   newCSVWriter,
+	"json":  func(out io.Writer) batchWriter { return &jsonWriter{enc: json.NewEncoder(out)} },
+}
+
+// Formats returns the names of the batch output formats, sorted by name
+func Formats() []string {
+	names := make([]string, 0, len(formats))
+	for name := range formats {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return names
+}
+
+// BatchLine is an evaluated line of a batch
+type BatchLine struct {
+	Line       int    `json:"line"` // Line number in the input, counting from 1
+	Expression string `json:"expression"`
+	Result     string `json:"result,omitempty"`
+	Error      string `json:"error,omitempty"`
+}
+
+// BatchSummary counts the evaluated lines of a batch
+type BatchSummary struct {
+	Evaluated int
+	Failed    int
+}
+
+// Batch evaluates every line of in as an expression with the mode, and writes the results in the format to out
+// Empty lines and lines starting with # are skipped, every line is evaluated on its own, so let and ans do not carry over
+//
+//	plain  one result per line, errors go to the error output with their line number, e.g line 3: column 3: division by zero
+//	csv    line,expression,result,error with a header
+//	json   one JSON object per line, e.g {"line":1,"expression":"1 + 2","result":"3"}
+//
+// The expressions are evaluated by a pool of goroutines, see BatchOptions, but the results are written in the order of the input
+// A line that fails or times out does not stop the batch, the summary tells how many failed
+// The error is returned when in cannot be read, out cannot be written, or ctx is cancelled before the end of in
+func (c *CLI) Batch(ctx context.Context, in io.Reader, mode, format string, opts BatchOptions) (BatchSummary, error) {
+	newWriter, ok := formats[format]
+	if !ok {
+		return BatchSummary{}, fmt.Errorf("unknown format %q, available formats are %s", format, strings.Join(Formats(), ", "))
+	}
+	w := newWriter(c.out)
+
+	ctx, cancel := context.WithCancel(ctx)
+	defer cancel()
+
+	var summary BatchSummary
+	var writeErr error
+	readErr := c.pool(ctx, in, mode, opts, func(line BatchLine) {
+		if writeErr != nil || ctx.Err() != nil {
+			return // Drain the results of the lines that were still running
+		}
+
+		summary.Evaluated++
+		if line.Error != "" {
+			summary.Failed++
+			if format == "plain" {
+				fmt.Fprintf(c.errOut, "line %d: %s\n", line.Line, line.Error)
+			}
+		}
+		if writeErr = w.write(line); writeErr != nil {
+			cancel()
+		}
+	})
+
+	if writeErr != nil {
+		return summary, writeErr
+	}
+	if err := w.flush(); err != nil {
+		return summary, err
+	}
+	return summary, readErr // The lines before a cancellation are written
+}
+
+// batchWriter writes the lines of a batch in one format
+type batchWriter interface {
+	write(line BatchLine) error
+	flush() error
+}
+
+type plainWriter struct {
+	out io.Writer
+}
+
+// write only writes results, errors were already written to the error output
+func (p *plainWriter) write(line BatchLine) error {
+	if line.Error != "" {
+		return nil
+	}
+	_, err := fmt.Fprintln(p.out, line.Result)
+	return err
+}
+
+func (p *plainWriter) flush() error {
+	return nil
+}
+
+type csvWriter struct {
+	w *csv.Writer
+}
+
+// newCSVWriter writes the header right away, so that an empty batch still has one
+func newCSVWriter(out io.Writer) batchWriter {
+	w := csv.NewWriter(out)
+	_ = w.Write([]string{"line", "expression", "result", "error"}) // Errors are returned by flush
+	return &csvWriter{w: w}
+}
+
+func (c *csvWriter) write(line BatchLine) error {
+	return c.w.Write([]string{strconv.Itoa(line.Line), line.Expression, line.Result, line.Error})
+}
+
+func (c *csvWriter) flush() error {
+	c.w.Flush()
+	return c.w.Error()
+}
+
+type jsonWriter struct {
+	enc *json.Encoder
+}
+
+func (j *jsonWriter) write(line BatchLine) error {
+	return j.enc.Encode(line)
+}
+
+func (j *jsonWriter) flush() error {
+	return nil
+}
@@ -0,0 +1,191 @@
+// Package cli is the command line adapter of the calculator, it prints the results of the app.Calculator port on a terminal
+package cli
+
+import (
+	"errors"
+	"fmt"
+	"io"
+	"strconv"
+	"strings"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+	"github.com/shashank-priyadarshi/training/calculator/app"
+	"github.com/shashank-priyadarshi/training/calculator/repl"
+	"github.com/shashank-priyadarshi/training/calculator/subtract"
+)
+
+// CLI runs the calculator from the command line
+type CLI struct {
+	calc   app.Calculator
+	in     io.Reader
+	out    io.Writer
+	errOut io.Writer // Errors that must not mix with the results, e.g when the output of a batch is piped
+}
+
+// New returns a CLI reading from in and writing to out and errOut
+func New(calc app.Calculator, in io.Reader, out, errOut io.Writer) *CLI {
+	return &CLI{calc: calc, in: in, out: out, errOut: errOut}
+}
+
+// Interactive starts the interactive calculator with the mode, or with the default mode when mode is empty
+// The REPL evaluates with sessions of the port, so its lines are recorded by the core like every other evaluation
+func (c *CLI) Interactive(mode string) error {
+	calc := repl.New(c.in, c.out)
+	if err := calc.SetSessions(c.calc.NewSession); err != nil {
+		return err
+	}
+	if mode != "" {
+		if err := calc.SetMode(mode); err != nil {
+			return err
+		}
+	}
+	return calc.Run()
+}
+
+// Call calls the function name with args, e.g Call("float", "pow", []string{"2", "10"}) prints 1024
+func (c *CLI) Call(mode, name string, args []string) error {
+	result, err := c.calc.Evaluate(name+"("+strings.Join(args, ", ")+")", mode)
+	if err != nil {
+		return err
+	}
+	fmt.Fprintln(c.out, result.Value)
+	return nil
+}
+
+// History runs a history command:
+//
+//	history               lists every recorded evaluation
+//	history search query  lists the evaluations whose expression or result contains query
+//	history replay id     evaluates the expression of an evaluation again, with the same mode
+//	history clear         removes every recorded evaluation
+func (c *CLI) History(args []string) error {
+	command := "list"
+	if len(args) > 0 {
+		command, args = args[0], args[1:]
+	}
+
+	switch {
+	case command == "list" && len(args) == 0:
+		return c.listHistory("")
+	case command == "search" && len(args) == 1:
+		return c.listHistory(args[0])
+	case command == "replay" && len(args) == 1:
+		id, err := strconv.Atoi(args[0])
+		if err != nil {
+			return fmt.Errorf("history replay: invalid id %q", args[0])
+		}
+		result, err := c.calc.Replay(id)
+		if errors.Is(err, app.ErrNotFound) || errors.Is(err, app.ErrNotReplayable) {
+			return fmt.Errorf("history replay %d: %w", id, err)
+		}
+		c.show(result.Expression, result.Value, err)
+		return nil
+	case command == "clear" && len(args) == 0:
+		return c.calc.ClearHistory()
+	default:
+		return errors.New("usage: history [list | search query | replay id | clear]")
+	}
+}
+
+func (c *CLI) listHistory(query string) error {
+	entries, err := c.calc.History(query)
+	if err != nil {
+		return err
+	}
+	for _, entry := range entries {
+		outcome := entry.Result
+		if entry.Error != "" {
+			outcome = "error: " + entry.Error
+		}
+		mode := entry.Mode
+		if entry.RPN {
+			mode += " rpn"
+		}
+		fmt.Fprintf(c.out, "%4d  %s  %-8s %s = %s\n", entry.ID, entry.Time.Format("2006-01-02 15:04:05"), mode, entry.Expression, outcome)
+	}
+	return nil
+}
+
+// Demo evaluates the example expressions with a few modes, or only with mode when it is not empty
+func (c *CLI) Demo(mode string) {
+	// subtract.Subtract used to swap the operands when a < b, so 1 - 2 printed 1
+	fmt.Fprintln(c.out, "Note: subtract.Subtract(1, 2) now returns", subtract.Subtract(1, 2), "instead of 1")
+	fmt.Fprintln(c.out, "Note: use subtract.AbsDiff(1, 2) for the old behaviour, it returns", subtract.AbsDiff(1, 2))
+	fmt.Fprintln(c.out)
+
+	// Each expression is parsed and evaluated by the expr package, which calls add, subtract, multiply and divide for us
+	expressions := []string{
+		"1 + 2",
+		"1 - 2", // It will print -1, see the note about subtract.Subtract above
+		"1 * 2",
+		"1 / 2", // It will print 0 in int mode, and 0.5 in float mode, Data types are important
+		"(3 + 4) * 2 / 7",
+		"-2 * (3 + 4)",
+		"1 / 0",                   // Dividing by 0 is a runtime error, not a compile time error
+		"9223372036854775807 * 2", // The result does not fit into an int, so it would silently wrap around without the check
+		"(3 + 4",                  // Parse errors tell the column of the offending token
+	}
+
+	// Numbers that do not fit into an int, and fractions that a float64 can only get close to
+	// The big modes use math/big, and are only limited by memory
+	bigExpressions := []string{
+		"3000000000 * 3000000000",
+		"3000000000 * 3000000000 * 3000000000", // Too big for an int, fine for a big.Int
+		"1 / 3",
+		"1 / 3 * 3", // Exactly 1 with big.Rat and rational.Rational
+	}
+
+	// Complex numbers have a real and an imaginary part, imaginary numbers end with i like in Go
+	complexExpressions := []string{
+		"(3+4i) * (1-2i)",
+		"1i * 1i", // i*i is -1
+		"abs(3+4i)",
+		"phase(1i)",
+		"conj(3+4i)",
+	}
+
+	// Quantities with units, adding meters to seconds fails like dividing by 0
+	unitExpressions := []string{
+		"5 km + 300 m",
+		"2 GiB / 512 MiB", // 4, the units cancel out
+		"100 km / 2 h",    // Printed in base units, m/s
+		"5 km + 3 s",
+	}
+
+	// The same expressions are evaluated with int, and then with float64
+	// The big expressions contrast int with the big modes, and with rat, which is exact but limited to int64
+	modes, bigModes := []string{"int", "float"}, []string{"int", "bigint", "bigrat", "bigfloat", "rat"}
+	complexModes, unitModes := []string{"complex"}, []string{"units"}
+	if mode != "" {
+		modes, bigModes, complexModes, unitModes = []string{mode}, []string{mode}, []string{mode}, []string{mode}
+	}
+	c.evaluateAll(modes, expressions)
+	c.evaluateAll(bigModes, bigExpressions)
+	c.evaluateAll(complexModes, complexExpressions)
+	c.evaluateAll(unitModes, unitExpressions)
+}
+
+// evaluateAll evaluates every expression with every mode, ans is not shared between expressions
+func (c *CLI) evaluateAll(modes []string, expressions []string) {
+	for _, mode := range modes {
+		fmt.Fprintln(c.out, "Mode:", mode)
+		for _, expression := range expressions {
+			result, err := c.calc.Evaluate(expression, mode)
+			c.show(expression, result.Value, err)
+		}
+	}
+}
+
+// show prints the result of an operation, or explains why the operation failed
+func (c *CLI) show(operation string, result string, err error) {
+	switch {
+	case err == nil:
+		fmt.Fprintln(c.out, operation+": ", result)
+	case errors.Is(err, calculator.ErrDivisionByZero):
+		fmt.Fprintln(c.out, operation+": ", "dividing by 0 is not allowed:", err)
+	case errors.Is(err, calculator.ErrOverflow):
+		fmt.Fprintln(c.out, operation+": ", "result is too big:", err)
+	default:
+		fmt.Fprintln(c.out, operation+": ", "failed:", err)
+	}
+}
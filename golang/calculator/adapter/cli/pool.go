@@ -0,0 +1,218 @@
+package cli
+
+import (
+	"bufio"
+	"bytes"
+	"context"
+	"errors"
+	"fmt"
+	"io"
+	"strings"
+	"sync"
+	"time"
+)
+
+// BatchOptions configures the worker pool of Batch
+type BatchOptions struct {
+	Workers   int           // Number of goroutines evaluating expressions at the same time, 1 when less than 1
+	Timeout   time.Duration // Time limit of one expression, no limit when 0
+	Abandoned int           // Limit of expressions that timed out but are still being evaluated, Workers when less than 1
+}
+
+// maxLineSize is the longest expression of a batch, as long as the body of a request to the HTTP adapter
+// A longer line fails on its own, the other lines are still evaluated
+const maxLineSize = 1 << 20
+
+// job is a line of the input, seq counts the evaluated lines from 0 and gives the order of the output
+type job struct {
+	seq  int
+	line BatchLine
+}
+
+// pool evaluates the lines of in with opts.Workers goroutines, and calls emit with the results in the order of the input
+// emit is only called by the goroutine that called pool, so it does not need a mutex
+//
+// The lines flow through three stages connected by channels, a pipeline:
+//
+//	reader   one goroutine reading the lines of in and sending them on jobs
+//	workers  opts.Workers goroutines receiving jobs, evaluating them and sending the results on results
+//	emitter  the calling goroutine, it receives the results in any order and puts them back in the order of the input
+//
+// A worker can finish line 3 before another worker finishes line 2, so the emitter keeps early results in pending
+// window limits the number of lines between the reader and the emitter, so pending cannot grow with the size of in
+// slots limits the evaluations running at the same time, see evaluate
+// The error is returned when in cannot be read or ctx is cancelled
+// Once ctx is cancelled, emit is not called anymore, not even for the lines that were evaluated before
+func (c *CLI) pool(ctx context.Context, in io.Reader, mode string, opts BatchOptions, emit func(BatchLine)) error {
+	workers := max(opts.Workers, 1)
+	abandoned := opts.Abandoned
+	if abandoned < 1 {
+		abandoned = workers
+	}
+	jobs := make(chan job)
+	results := make(chan job)
+	window := make(chan struct{}, 4*workers)        // A token for every line that was read but not emitted yet
+	slots := make(chan struct{}, workers+abandoned) // A token for every evaluation that is still running
+
+	var readErr error
+	go func() {
+		defer close(jobs)
+		readErr = read(ctx, in, jobs, window)
+	}()
+
+	var wg sync.WaitGroup
+	for i := 0; i < workers; i++ {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			for j := range jobs {
+				if j.line.Error == "" { // A line that is too long failed when it was read
+					j.line = c.evaluate(ctx, mode, opts.Timeout, j.line, slots)
+				}
+				results <- j
+			}
+		}()
+	}
+	go func() {
+		wg.Wait()
+		close(results) // No worker sends anymore, so the emitter can stop ranging over results
+	}()
+
+	pending := map[int]BatchLine{}
+	next := 0
+	for r := range results {
+		if ctx.Err() != nil {
+			continue // Drain the results, so that the workers can send them and exit
+		}
+		pending[r.seq] = r.line
+		for line, ok := pending[next]; ok && ctx.Err() == nil; line, ok = pending[next] {
+			delete(pending, next)
+			emit(line)
+			<-window
+			next++
+		}
+	}
+
+	// results is closed after jobs is closed, so readErr was written before and can be read without a data race
+	if readErr != nil {
+		return readErr
+	}
+	return ctx.Err()
+}
+
+// read sends the expressions of in on jobs, it stops at the end of in or when ctx is cancelled
+// ctx is checked before every send: when a send and ctx.Done are both ready, select picks one of them at random
+func read(ctx context.Context, in io.Reader, jobs chan<- job, window chan struct{}) error {
+	reader := bufio.NewReader(in)
+	seq := 0
+	for n := 1; ; n++ {
+		data, tooLong, err := readLine(reader)
+		if err != nil && !errors.Is(err, io.EOF) {
+			return err
+		}
+
+		line := BatchLine{Line: n, Expression: strings.TrimSpace(string(data))}
+		if tooLong {
+			line.Error = fmt.Sprintf("the line is longer than %d bytes", maxLineSize)
+		}
+		if line.Error != "" || (line.Expression != "" && !strings.HasPrefix(line.Expression, "#")) {
+			// Wait for room in the window, and then for a free worker
+			if ctx.Err() != nil {
+				return nil // pool returns ctx.Err()
+			}
+			select {
+			case window <- struct{}{}:
+			case <-ctx.Done():
+				return nil
+			}
+			if ctx.Err() != nil {
+				return nil
+			}
+			select {
+			case jobs <- job{seq: seq, line: line}:
+				seq++
+			case <-ctx.Done():
+				return nil
+			}
+		}
+
+		if err != nil { // io.EOF, the last line has no line break
+			return nil
+		}
+	}
+}
+
+// readLine returns the next line of r without its line break, tooLong reports a line longer than maxLineSize
+// The rest of a long line is skipped without keeping it in memory, so the next call returns the next line
+func readLine(r *bufio.Reader) ([]byte, bool, error) {
+	var line []byte
+	tooLong := false
+	for {
+		chunk, err := r.ReadSlice('\n')
+		chunk = bytes.TrimSuffix(chunk, []byte("\n"))
+		if tooLong || len(line)+len(chunk) > maxLineSize {
+			line, tooLong = nil, true
+		} else {
+			line = append(line, chunk...)
+		}
+		if !errors.Is(err, bufio.ErrBufferFull) {
+			return line, tooLong, err
+		}
+	}
+}
+
+// evaluate evaluates one line, it gives up when the timeout expires or ctx is cancelled
+// The expressions cannot be interrupted, so the goroutine evaluating an expression that timed out keeps running until it
+// finishes, done is buffered so that it can then send its result and exit even though nobody receives it anymore
+//
+// Such goroutines would pile up when many expressions time out, so every evaluation takes a token from slots before
+// it starts and gives it back when it finishes, also after a timeout
+// When slots is full, the next line waits for an abandoned evaluation to finish, within its own timeout
+func (c *CLI) evaluate(ctx context.Context, mode string, timeout time.Duration, line BatchLine, slots chan struct{}) BatchLine {
+	if timeout > 0 {
+		var cancel context.CancelFunc
+		ctx, cancel = context.WithTimeout(ctx, timeout)
+		defer cancel()
+	}
+
+	select {
+	case slots <- struct{}{}:
+	case <-ctx.Done():
+		return interrupted(ctx, timeout, line)
+	}
+	// Both cases can be ready, a cancelled line must not start evaluating because a slot was free
+	if ctx.Err() != nil {
+		<-slots
+		return interrupted(ctx, timeout, line)
+	}
+
+	done := make(chan BatchLine, 1)
+	go func() {
+		defer func() { <-slots }()
+		evaluated := line
+		result, err := c.calc.Evaluate(line.Expression, mode)
+		if err != nil {
+			evaluated.Error = err.Error()
+		} else {
+			evaluated.Result = result.Value
+		}
+		done <- evaluated
+	}()
+
+	select {
+	case evaluated := <-done:
+		return evaluated // Also when ctx is done at the same time, pool drops the results after a cancellation
+	case <-ctx.Done():
+		return interrupted(ctx, timeout, line)
+	}
+}
+
+// interrupted returns the line failed with the reason ctx is done
+func interrupted(ctx context.Context, timeout time.Duration, line BatchLine) BatchLine {
+	if ctx.Err() == context.DeadlineExceeded {
+		line.Error = fmt.Sprintf("timed out after %v", timeout)
+	} else {
+		line.Error = ctx.Err().Error()
+	}
+	return line
+}
@@ -0,0 +1,165 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"io"
+	"strconv"
+	"strings"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"github.com/shashank-priyadarshi/training/calculator/app"
+)
+
+// fakeCalc is an app.Calculator whose Evaluate calls evaluate, Batch calls no other method
+// The embedded interface is nil, calling one of its methods panics
+type fakeCalc struct {
+	app.Calculator
+	evaluate func(expression string) (string, error)
+}
+
+func (f fakeCalc) Evaluate(expression, mode string) (app.Result, error) {
+	v, err := f.evaluate(expression)
+	return app.Result{Expression: expression, Mode: mode, Value: v}, err
+}
+
+func batch(ctx context.Context, evaluate func(string) (string, error), input string, opts BatchOptions) (string, BatchSummary, error) {
+	var out strings.Builder
+	c := New(fakeCalc{evaluate: evaluate}, nil, &out, io.Discard)
+	summary, err := c.Batch(ctx, strings.NewReader(input), "int", "plain", opts)
+	return out.String(), summary, err
+}
+
+func TestPoolKeepsTheOrderOfTheInput(t *testing.T) {
+	// Every line sleeps as many milliseconds as it says, so the first lines finish last
+	sleep := func(expression string) (string, error) {
+		ms, err := strconv.Atoi(expression)
+		time.Sleep(time.Duration(ms) * time.Millisecond)
+		return expression, err
+	}
+
+	out, summary, err := batch(context.Background(), sleep, "40\n30\n\n# a comment\n20\n10\n0\n", BatchOptions{Workers: 4})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if want := "40\n30\n20\n10\n0\n"; out != want {
+		t.Errorf("output %q, want %q", out, want)
+	}
+	if summary != (BatchSummary{Evaluated: 5}) {
+		t.Errorf("summary %+v, want 5 evaluated lines", summary)
+	}
+}
+
+func TestPoolStopsWhenCancelled(t *testing.T) {
+	ctx, cancel := context.WithCancel(context.Background())
+	defer cancel()
+	var calls atomic.Int32
+	evaluate := func(expression string) (string, error) {
+		calls.Add(1)
+		cancel() // Like Ctrl+C while the first line runs
+		return expression, nil
+	}
+
+	// With one worker, the next line is only taken after the first one was evaluated, when ctx is already cancelled
+	out, summary, err := batch(ctx, evaluate, "1\n"+strings.Repeat("after\n", 100), BatchOptions{Workers: 1})
+	if !errors.Is(err, context.Canceled) {
+		t.Errorf("err = %v, want context.Canceled", err)
+	}
+	if out != "" || summary != (BatchSummary{}) {
+		t.Errorf("output %q, summary %+v, nothing may be written once the batch is cancelled", out, summary)
+	}
+	if got := calls.Load(); got != 1 {
+		t.Errorf("%d lines were evaluated, want 1, no line may start after the cancellation", got)
+	}
+}
+
+func TestPoolFailsLongLinesOnTheirOwn(t *testing.T) {
+	echo := func(expression string) (string, error) { return expression, nil }
+	input := "1\n" + strings.Repeat("2", maxLineSize+1) + "\n3"
+
+	var out, errOut strings.Builder
+	c := New(fakeCalc{evaluate: echo}, nil, &out, &errOut)
+	summary, err := c.Batch(context.Background(), strings.NewReader(input), "int", "plain", BatchOptions{Workers: 2})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if out.String() != "1\n3\n" {
+		t.Errorf("output %q, want the lines before and after the long one, the last line has no line break", out.String())
+	}
+	if want := fmt.Sprintf("line 2: the line is longer than %d bytes\n", maxLineSize); errOut.String() != want {
+		t.Errorf("error output %q, want %q", errOut.String(), want)
+	}
+	if summary != (BatchSummary{Evaluated: 3, Failed: 1}) {
+		t.Errorf("summary %+v, want 3 evaluated lines and 1 failed", summary)
+	}
+
+	// A line of exactly maxLineSize bytes is not too long
+	exact := strings.Repeat("4", maxLineSize)
+	out.Reset()
+	_, err = c.Batch(context.Background(), strings.NewReader(exact+"\n"), "int", "plain", BatchOptions{})
+	if err != nil || out.String() != exact+"\n" {
+		t.Errorf("a line of %d bytes: %d bytes written, %v, want the line", maxLineSize, out.Len(), err)
+	}
+}
+
+func TestPoolTimesOutOneExpression(t *testing.T) {
+	release := make(chan struct{})
+	defer close(release) // Lets the abandoned evaluation finish
+	evaluate := func(expression string) (string, error) {
+		if expression == "slow" {
+			<-release
+		}
+		return expression, nil
+	}
+
+	var out, errOut strings.Builder
+	c := New(fakeCalc{evaluate: evaluate}, nil, &out, &errOut)
+	opts := BatchOptions{Workers: 2, Timeout: 20 * time.Millisecond}
+	summary, err := c.Batch(context.Background(), strings.NewReader("1\nslow\n3\n"), "int", "plain", opts)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if out.String() != "1\n3\n" {
+		t.Errorf("output %q, want the results of the other lines", out.String())
+	}
+	if want := "line 2: timed out after 20ms\n"; errOut.String() != want {
+		t.Errorf("error output %q, want %q", errOut.String(), want)
+	}
+	if summary != (BatchSummary{Evaluated: 3, Failed: 1}) {
+		t.Errorf("summary %+v, want 3 evaluated lines and 1 failed", summary)
+	}
+}
+
+func TestPoolLimitsAbandonedEvaluations(t *testing.T) {
+	release := make(chan struct{})
+	defer close(release)
+	var running, most atomic.Int32
+	evaluate := func(expression string) (string, error) {
+		n := running.Add(1)
+		defer running.Add(-1)
+		for m := most.Load(); n > m; m = most.Load() {
+			if most.CompareAndSwap(m, n) {
+				break
+			}
+		}
+		<-release // Every expression hangs until the test ends
+		return expression, nil
+	}
+
+	// One worker and one abandoned evaluation: the second line starts while the first still runs, the others wait
+	// for a slot until they time out, without starting another goroutine
+	opts := BatchOptions{Workers: 1, Timeout: 10 * time.Millisecond, Abandoned: 1}
+	_, summary, err := batch(context.Background(), evaluate, strings.Repeat("hang\n", 10), opts)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if summary != (BatchSummary{Evaluated: 10, Failed: 10}) {
+		t.Errorf("summary %+v, want 10 evaluated lines that all timed out", summary)
+	}
+	if got := most.Load(); got != 2 {
+		t.Errorf("%d evaluations ran at the same time, want 2, one for the worker and one abandoned", got)
+	}
+}
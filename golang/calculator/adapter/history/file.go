@@ -0,0 +1,164 @@
+package history
+
+import (
+	"bufio"
+	"bytes"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"io/fs"
+	"os"
+	"sync"
+	"time"
+
+	"github.com/shashank-priyadarshi/training/calculator/app"
+)
+
+// record is an entry as it is stored in the file, the JSON names are part of the file format and must not change
+type record struct {
+	ID         int       `json:"id"`
+	Expression string    `json:"expression"`
+	Mode       string    `json:"mode"`
+	Result     string    `json:"result,omitempty"`
+	Error      string    `json:"error,omitempty"`
+	RPN        bool      `json:"rpn,omitempty"`
+	Time       time.Time `json:"time"`
+}
+
+// counter is the line Clear leaves in the file, the ID of the next entry
+// Without it, the IDs would start at 1 again when the file is opened, and an old ID would refer to a new entry
+type counter struct {
+	NextID int `json:"next_id"`
+}
+
+// stored is a line of the file, either a record or a counter, only a counter has a NextID
+type stored struct {
+	record
+	counter
+}
+
+// File is a history kept in a JSON lines file, an entry is appended to the file as soon as it is added
+// It is safe for concurrent use within a program, but not by several programs sharing the file
+type File struct {
+	mu     sync.Mutex
+	path   string
+	nextID int
+}
+
+// OpenFile returns the history stored at path, the file is created by the first Add when it does not exist
+func OpenFile(path string) (*File, error) {
+	f := &File{path: path}
+	_, nextID, err := f.read()
+	if err != nil {
+		return nil, err
+	}
+	f.nextID = nextID
+	return f, nil
+}
+
+func (f *File) Add(entry app.HistoryEntry) (app.HistoryEntry, error) {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+
+	entry.ID = f.nextID
+	line, err := json.Marshal(record(entry))
+	if err != nil {
+		return app.HistoryEntry{}, err
+	}
+
+	file, err := os.OpenFile(f.path, os.O_APPEND|os.O_CREATE|os.O_WRONLY, 0o644)
+	if err != nil {
+		return app.HistoryEntry{}, err
+	}
+	if _, err := file.Write(append(line, '\n')); err != nil {
+		file.Close()
+		return app.HistoryEntry{}, err
+	}
+	if err := file.Close(); err != nil {
+		return app.HistoryEntry{}, err
+	}
+
+	f.nextID++
+	return entry, nil
+}
+
+func (f *File) List() ([]app.HistoryEntry, error) {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+
+	entries, _, err := f.read()
+	return entries, err
+}
+
+func (f *File) Get(id int) (app.HistoryEntry, error) {
+	entries, err := f.List()
+	if err != nil {
+		return app.HistoryEntry{}, err
+	}
+	for _, entry := range entries {
+		if entry.ID == id {
+			return entry, nil
+		}
+	}
+	return app.HistoryEntry{}, app.ErrNotFound
+}
+
+func (f *File) Search(query string) ([]app.HistoryEntry, error) {
+	entries, err := f.List()
+	if err != nil {
+		return nil, err
+	}
+	return filter(entries, query), nil
+}
+
+// Clear replaces the entries of the file with the ID of the next entry, IDs are not reused, not even after the file
+// is opened again
+func (f *File) Clear() error {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+
+	data, err := json.Marshal(counter{NextID: f.nextID})
+	if err != nil {
+		return err
+	}
+	return os.WriteFile(f.path, append(data, '\n'), 0o644)
+}
+
+// read returns all entries of the file and the ID of the next entry, a missing file is an empty history
+func (f *File) read() ([]app.HistoryEntry, int, error) {
+	file, err := os.Open(f.path)
+	if errors.Is(err, fs.ErrNotExist) {
+		return nil, 1, nil
+	}
+	if err != nil {
+		return nil, 0, err
+	}
+	defer file.Close()
+
+	var entries []app.HistoryEntry
+	nextID := 1
+	// A bufio.Scanner stops at lines longer than 64 KiB, but an expression can be as long as a request body of the
+	// HTTP adapter, ReadBytes has no limit
+	reader := bufio.NewReader(file)
+	for n := 1; ; n++ {
+		data, readErr := reader.ReadBytes('\n')
+		if len(bytes.TrimSpace(data)) > 0 {
+			var l stored
+			if err := json.Unmarshal(data, &l); err != nil {
+				return nil, 0, fmt.Errorf("%s:%d: %w", f.path, n, err)
+			}
+			if l.NextID == 0 {
+				entries = append(entries, app.HistoryEntry(l.record))
+				l.NextID = l.ID + 1
+			}
+			nextID = max(nextID, l.NextID)
+		}
+		if errors.Is(readErr, io.EOF) {
+			return entries, nextID, nil
+		}
+		if readErr != nil {
+			return nil, 0, readErr
+		}
+	}
+}
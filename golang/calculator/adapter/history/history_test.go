@@ -0,0 +1,140 @@
+package history_test
+
+import (
+	"errors"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/shashank-priyadarshi/training/calculator/adapter/history"
+	"github.com/shashank-priyadarshi/training/calculator/app"
+)
+
+// repositories returns a new, empty repository of every adapter, the same tests run against all of them
+func repositories(t *testing.T) map[string]app.HistoryRepository {
+	file, err := history.OpenFile(filepath.Join(t.TempDir(), "history.jsonl"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	return map[string]app.HistoryRepository{"Memory": history.NewMemory(), "File": file}
+}
+
+func entry(expression, result string) app.HistoryEntry {
+	return app.HistoryEntry{Expression: expression, Mode: "int", Result: result, Time: time.Date(2024, 1, 2, 3, 4, 5, 0, time.UTC)}
+}
+
+func TestRepository(t *testing.T) {
+	for name, repo := range repositories(t) {
+		t.Run(name, func(t *testing.T) {
+			for i, e := range []app.HistoryEntry{entry("1 + 2", "3"), entry("6 / 2", "3"), entry("2 * 5", "10")} {
+				added, err := repo.Add(e)
+				if err != nil {
+					t.Fatal(err)
+				}
+				if added.ID != i+1 {
+					t.Errorf("Add(%s) ID = %d, want %d", e.Expression, added.ID, i+1)
+				}
+			}
+
+			entries, err := repo.List()
+			if err != nil || len(entries) != 3 || entries[0].Expression != "1 + 2" || entries[2].Expression != "2 * 5" {
+				t.Errorf("List() = %+v, %v, want the three entries, oldest first", entries, err)
+			}
+
+			got, err := repo.Get(2)
+			if err != nil || got.Expression != "6 / 2" || !got.Time.Equal(entry("", "").Time) {
+				t.Errorf("Get(2) = %+v, %v, want the entry of 6 / 2", got, err)
+			}
+			if _, err := repo.Get(4); !errors.Is(err, app.ErrNotFound) {
+				t.Errorf("Get(4) err = %v, want ErrNotFound", err)
+			}
+
+			// The query matches the expression or the result
+			for query, want := range map[string]int{"3": 2, "/": 1, "10": 1, "7": 0} {
+				found, err := repo.Search(query)
+				if err != nil || len(found) != want {
+					t.Errorf("Search(%q) = %+v, %v, want %d entries", query, found, err, want)
+				}
+			}
+
+			if err := repo.Clear(); err != nil {
+				t.Fatal(err)
+			}
+			if entries, _ := repo.List(); len(entries) != 0 {
+				t.Errorf("List() after Clear = %+v, want no entries", entries)
+			}
+			added, err := repo.Add(entry("1 - 1", "0"))
+			if err != nil || added.ID != 4 {
+				t.Errorf("Add after Clear = %+v, %v, want ID 4, IDs are not reused", added, err)
+			}
+		})
+	}
+}
+
+func TestFileKeepsTheNextIDAfterClear(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "history.jsonl")
+	file, err := history.OpenFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	_, _ = file.Add(entry("1 + 2", "3"))
+	_, _ = file.Add(entry("2 + 3", "5"))
+	if err := file.Clear(); err != nil {
+		t.Fatal(err)
+	}
+
+	reopened, err := history.OpenFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	added, err := reopened.Add(entry("3 + 4", "7"))
+	if err != nil || added.ID != 3 {
+		t.Errorf("Add after reopening = %+v, %v, want ID 3, a replay of ID 1 must not find a new entry", added, err)
+	}
+	if _, err := reopened.Get(1); !errors.Is(err, app.ErrNotFound) {
+		t.Errorf("Get(1) err = %v, want ErrNotFound", err)
+	}
+}
+
+func TestFileKeepsRPNLines(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "history.jsonl")
+	file, err := history.OpenFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	rpn := entry("1 2 +", "3")
+	rpn.RPN = true
+	_, _ = file.Add(rpn)
+	_, _ = file.Add(entry("1 + 2", "3"))
+
+	reopened, err := history.OpenFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	entries, err := reopened.List()
+	if err != nil || len(entries) != 2 || !entries[0].RPN || entries[1].RPN {
+		t.Errorf("List after reopening = %+v, %v, want only the first entry marked as RPN", entries, err)
+	}
+}
+
+func TestFileReadsLongExpressions(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "history.jsonl")
+	file, err := history.OpenFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	// Longer than the 64 KiB limit of a bufio.Scanner, and longer still in the file, json.Marshal writes < as \u003c
+	long := strings.Repeat("1 < ", 1<<18)
+	_, _ = file.Add(entry(long, ""))
+	_, _ = file.Add(entry("1 + 2", "3"))
+
+	reopened, err := history.OpenFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	entries, err := reopened.List()
+	if err != nil || len(entries) != 2 || entries[0].Expression != long {
+		t.Fatalf("List() returned %d entries, %v, want 2 with the long expression first", len(entries), err)
+	}
+}
@@ -0,0 +1,78 @@
+// Package history implements the app.HistoryRepository port: Memory keeps the history until the program exits,
+// and File keeps it in a file with one JSON object per line (JSON lines), so it survives restarts
+package history
+
+import (
+	"strings"
+	"sync"
+
+	"github.com/shashank-priyadarshi/training/calculator/app"
+)
+
+// Memory is a history kept in memory, it is safe for concurrent use
+type Memory struct {
+	mu      sync.Mutex
+	entries []app.HistoryEntry
+	nextID  int
+}
+
+// NewMemory returns an empty history
+func NewMemory() *Memory {
+	return &Memory{nextID: 1}
+}
+
+func (m *Memory) Add(entry app.HistoryEntry) (app.HistoryEntry, error) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+
+	entry.ID = m.nextID
+	m.nextID++
+	m.entries = append(m.entries, entry)
+	return entry, nil
+}
+
+func (m *Memory) List() ([]app.HistoryEntry, error) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+
+	return append([]app.HistoryEntry(nil), m.entries...), nil
+}
+
+func (m *Memory) Get(id int) (app.HistoryEntry, error) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+
+	for _, entry := range m.entries {
+		if entry.ID == id {
+			return entry, nil
+		}
+	}
+	return app.HistoryEntry{}, app.ErrNotFound
+}
+
+func (m *Memory) Search(query string) ([]app.HistoryEntry, error) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+
+	return filter(m.entries, query), nil
+}
+
+// Clear removes all entries, IDs are not reused
+func (m *Memory) Clear() error {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+
+	m.entries = nil
+	return nil
+}
+
+// filter returns the entries whose expression or result contains query
+func filter(entries []app.HistoryEntry, query string) []app.HistoryEntry {
+	var found []app.HistoryEntry
+	for _, entry := range entries {
+		if strings.Contains(entry.Expression, query) || strings.Contains(entry.Result, query) {
+			found = append(found, entry)
+		}
+	}
+	return found
+}
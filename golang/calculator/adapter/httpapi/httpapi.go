@@ -0,0 +1,237 @@
+// Package httpapi is the HTTP adapter of the calculator, it translates HTTP requests with JSON bodies into calls of the app.Calculator port
+//
+//	POST /evaluate    {"expression": "1 / 2", "mode": "float"} returns {"expression": "1 / 2", "mode": "float", "result": "0.5"}
+//	GET  /operations  returns the operators and functions
+//
+//	GET    /history?q=query  returns the recorded evaluations, only the ones containing query when q is given
+//	DELETE /history          removes every recorded evaluation
+//	POST   /history/replay   {"id": 3} evaluates the expression of an evaluation again, the response is the one of /evaluate
+//
+// The handler needs no running server, so it can be tried with net/http/httptest:
+//
+//	rec := httptest.NewRecorder()
+//	req := httptest.NewRequest(http.MethodPost, "/evaluate", strings.NewReader(`{"expression": "1 + 2"}`))
+//	httpapi.NewHandler(app.NewService(history.NewMemory())).ServeHTTP(rec, req)
+//	// rec.Code is 200 and rec.Body is {"expression":"1 + 2","mode":"int","result":"3"}
+package httpapi
+
+import (
+	"encoding/json"
+	"errors"
+	"net/http"
+	"time"
+
+	"github.com/shashank-priyadarshi/training/calculator/app"
+	"github.com/shashank-priyadarshi/training/calculator/expr"
+)
+
+// maxBodySize limits the size of a request body, expressions are short
+const maxBodySize = 1 << 20
+
+// Timeouts of the server returned by NewServer
+const (
+	readHeaderTimeout = 5 * time.Second  // Time to read the headers of a request
+	readTimeout       = 30 * time.Second // Time to read a whole request, including a body of maxBodySize
+)
+
+// EvaluateRequest is the body of POST /evaluate, mode is optional
+type EvaluateRequest struct {
+	Expression string `json:"expression"`
+	Mode       string `json:"mode,omitempty"`
+}
+
+// EvaluateResponse is the body of a successful POST /evaluate
+type EvaluateResponse struct {
+	Expression string `json:"expression"`
+	Mode       string `json:"mode"`
+	Result     string `json:"result"`
+}
+
+// Operation is an element of the body of GET /operations
+type Operation struct {
+	Name        string `json:"name"`
+	Symbol      string `json:"symbol,omitempty"`
+	Arity       int    `json:"arity"`
+	Precedence  int    `json:"precedence"`
+	Description string `json:"description"`
+}
+
+// HistoryEntry is an element of the body of GET /history
+type HistoryEntry struct {
+	ID         int       `json:"id"`
+	Expression string    `json:"expression"`
+	Mode       string    `json:"mode"`
+	Result     string    `json:"result,omitempty"`
+	Error      string    `json:"error,omitempty"`
+	RPN        bool      `json:"rpn,omitempty"`
+	Time       time.Time `json:"time"`
+}
+
+// ReplayRequest is the body of POST /history/replay
+type ReplayRequest struct {
+	ID int `json:"id"`
+}
+
+// ErrorResponse is the body of every failed request
+type ErrorResponse struct {
+	Error  string `json:"error"`
+	Column int    `json:"column,omitempty"` // Column of the expression that failed, counting from 1
+}
+
+// NewHandler returns the HTTP handler of the calculator, it only depends on the port and not on the core
+func NewHandler(calc app.Calculator) http.Handler {
+	h := &handler{calc: calc}
+	mux := http.NewServeMux()
+	mux.HandleFunc("/evaluate", h.evaluate)
+	mux.HandleFunc("/operations", h.operations)
+	mux.HandleFunc("/history", h.history)
+	mux.HandleFunc("/history/replay", h.replay)
+	return mux
+}
+
+// NewServer returns a server of the handler of NewHandler on addr, e.g :8080
+// http.ListenAndServe has no timeouts, a client that sends a request one byte at a time would hold its connection open
+// forever, and enough of them would use up the connections of the server
+func NewServer(addr string, calc app.Calculator) *http.Server {
+	return &http.Server{
+		Addr:              addr,
+		Handler:           NewHandler(calc),
+		ReadHeaderTimeout: readHeaderTimeout,
+		ReadTimeout:       readTimeout,
+	}
+}
+
+type handler struct {
+	calc app.Calculator
+}
+
+func (h *handler) evaluate(w http.ResponseWriter, r *http.Request) {
+	if r.Method != http.MethodPost {
+		methodNotAllowed(w, http.MethodPost)
+		return
+	}
+
+	var req EvaluateRequest
+	if !decode(w, r, &req) {
+		return
+	}
+	if req.Expression == "" {
+		writeJSON(w, http.StatusBadRequest, ErrorResponse{Error: "expression is required"})
+		return
+	}
+
+	result, err := h.calc.Evaluate(req.Expression, req.Mode)
+	if err != nil {
+		writeError(w, err)
+		return
+	}
+	writeJSON(w, http.StatusOK, EvaluateResponse{Expression: result.Expression, Mode: result.Mode, Result: result.Value})
+}
+
+func (h *handler) history(w http.ResponseWriter, r *http.Request) {
+	switch r.Method {
+	case http.MethodGet:
+		entries, err := h.calc.History(r.URL.Query().Get("q"))
+		if err != nil {
+			writeError(w, err)
+			return
+		}
+		body := make([]HistoryEntry, len(entries))
+		for i, entry := range entries {
+			body[i] = HistoryEntry(entry)
+		}
+		writeJSON(w, http.StatusOK, body)
+	case http.MethodDelete:
+		if err := h.calc.ClearHistory(); err != nil {
+			writeError(w, err)
+			return
+		}
+		w.WriteHeader(http.StatusNoContent)
+	default:
+		methodNotAllowed(w, http.MethodGet+", "+http.MethodDelete)
+	}
+}
+
+func (h *handler) replay(w http.ResponseWriter, r *http.Request) {
+	if r.Method != http.MethodPost {
+		methodNotAllowed(w, http.MethodPost)
+		return
+	}
+
+	var req ReplayRequest
+	if !decode(w, r, &req) {
+		return
+	}
+
+	result, err := h.calc.Replay(req.ID)
+	if err != nil {
+		writeError(w, err)
+		return
+	}
+	writeJSON(w, http.StatusOK, EvaluateResponse{Expression: result.Expression, Mode: result.Mode, Result: result.Value})
+}
+
+func (h *handler) operations(w http.ResponseWriter, r *http.Request) {
+	if r.Method != http.MethodGet {
+		methodNotAllowed(w, http.MethodGet)
+		return
+	}
+
+	infos := h.calc.Operations()
+	ops := make([]Operation, len(infos))
+	for i, info := range infos {
+		ops[i] = Operation{
+			Name:        info.Name,
+			Symbol:      info.Symbol,
+			Arity:       info.Arity,
+			Precedence:  info.Precedence,
+			Description: info.Description,
+		}
+	}
+	writeJSON(w, http.StatusOK, ops)
+}
+
+// writeError picks the status code from the error returned by the port
+// Expressions that cannot be parsed and unknown modes are the fault of the client, 400
+// Expressions that parse but cannot be evaluated, e.g 1 / 0, and RPN lines that cannot be replayed are 422
+// Unknown history entries are 404
+func writeError(w http.ResponseWriter, err error) {
+	var syntaxErr *expr.SyntaxError
+	var evalErr *expr.EvalError
+	switch {
+	case errors.As(err, &syntaxErr):
+		writeJSON(w, http.StatusBadRequest, ErrorResponse{Error: syntaxErr.Msg, Column: syntaxErr.Col})
+	case errors.As(err, &evalErr):
+		writeJSON(w, http.StatusUnprocessableEntity, ErrorResponse{Error: evalErr.Err.Error(), Column: evalErr.Col})
+	case errors.Is(err, app.ErrNotReplayable):
+		writeJSON(w, http.StatusUnprocessableEntity, ErrorResponse{Error: err.Error()})
+	case errors.Is(err, expr.ErrUnknownMode):
+		writeJSON(w, http.StatusBadRequest, ErrorResponse{Error: err.Error()})
+	case errors.Is(err, app.ErrNotFound):
+		writeJSON(w, http.StatusNotFound, ErrorResponse{Error: err.Error()})
+	default:
+		writeJSON(w, http.StatusInternalServerError, ErrorResponse{Error: err.Error()})
+	}
+}
+
+// decode reads the JSON body of r into v, and writes a 400 response when the body is invalid
+func decode(w http.ResponseWriter, r *http.Request, v any) bool {
+	decoder := json.NewDecoder(http.MaxBytesReader(w, r.Body, maxBodySize))
+	decoder.DisallowUnknownFields()
+	if err := decoder.Decode(v); err != nil {
+		writeJSON(w, http.StatusBadRequest, ErrorResponse{Error: "invalid request body: " + err.Error()})
+		return false
+	}
+	return true
+}
+
+func methodNotAllowed(w http.ResponseWriter, allowed string) {
+	w.Header().Set("Allow", allowed)
+	writeJSON(w, http.StatusMethodNotAllowed, ErrorResponse{Error: "method not allowed, use " + allowed})
+}
+
+func writeJSON(w http.ResponseWriter, status int, body any) {
+	w.Header().Set("Content-Type", "application/json")
+	w.WriteHeader(status)
+	_ = json.NewEncoder(w).Encode(body)
+}
@@ -0,0 +1,165 @@
+package httpapi
+
+import (
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+
+	"github.com/shashank-priyadarshi/training/calculator/adapter/history"
+	"github.com/shashank-priyadarshi/training/calculator/app"
+)
+
+func newHandler() http.Handler {
+	return NewHandler(app.NewService(history.NewMemory()))
+}
+
+// serve sends a request to the handler without a running server, and returns the response
+func serve(h http.Handler, method, target, body string) *httptest.ResponseRecorder {
+	rec := httptest.NewRecorder()
+	h.ServeHTTP(rec, httptest.NewRequest(method, target, strings.NewReader(body)))
+	return rec
+}
+
+// decodeBody reads the JSON body of the response into a T
+func decodeBody[T any](t *testing.T, rec *httptest.ResponseRecorder) T {
+	t.Helper()
+	var v T
+	if err := json.NewDecoder(rec.Body).Decode(&v); err != nil {
+		t.Fatalf("decoding %T: %v", v, err)
+	}
+	return v
+}
+
+func TestEvaluate(t *testing.T) {
+	for _, tt := range []struct {
+		body string
+		want EvaluateResponse
+	}{
+		{`{"expression": "1 + 2"}`, EvaluateResponse{Expression: "1 + 2", Mode: "int", Result: "3"}},
+		{`{"expression": "1 / 2"}`, EvaluateResponse{Expression: "1 / 2", Mode: "int", Result: "0"}},
+		{`{"expression": "1 / 2", "mode": "float"}`, EvaluateResponse{Expression: "1 / 2", Mode: "float", Result: "0.5"}},
+	} {
+		rec := serve(newHandler(), http.MethodPost, "/evaluate", tt.body)
+		if rec.Code != http.StatusOK {
+			t.Errorf("POST /evaluate %s: status %d, want 200, body %s", tt.body, rec.Code, rec.Body)
+			continue
+		}
+		if ct := rec.Header().Get("Content-Type"); ct != "application/json" {
+			t.Errorf("POST /evaluate %s: Content-Type %q, want application/json", tt.body, ct)
+		}
+		if got := decodeBody[EvaluateResponse](t, rec); got != tt.want {
+			t.Errorf("POST /evaluate %s = %+v, want %+v", tt.body, got, tt.want)
+		}
+	}
+}
+
+func TestEvaluateErrors(t *testing.T) {
+	for _, tt := range []struct {
+		name   string
+		body   string
+		status int
+		column bool // The error points at a column of the expression
+	}{
+		{"syntax error", `{"expression": "(3 + 4"}`, http.StatusBadRequest, true},
+		{"division by zero", `{"expression": "1 / 0"}`, http.StatusUnprocessableEntity, true},
+		{"overflow", `{"expression": "9223372036854775807 + 1"}`, http.StatusUnprocessableEntity, true},
+		{"unknown mode", `{"expression": "1", "mode": "roman"}`, http.StatusBadRequest, false},
+		{"no expression", `{}`, http.StatusBadRequest, false},
+		{"unknown field", `{"expression": "1", "precision": 2}`, http.StatusBadRequest, false},
+		{"invalid JSON", `{"expression": `, http.StatusBadRequest, false},
+		{"body too large", `{"expression": "` + strings.Repeat("1", maxBodySize) + `"}`, http.StatusBadRequest, false},
+	} {
+		rec := serve(newHandler(), http.MethodPost, "/evaluate", tt.body)
+		if rec.Code != tt.status {
+			t.Errorf("%s: status %d, want %d, body %s", tt.name, rec.Code, tt.status, rec.Body)
+			continue
+		}
+		got := decodeBody[ErrorResponse](t, rec)
+		if got.Error == "" {
+			t.Errorf("%s: the response has no error", tt.name)
+		}
+		if (got.Column != 0) != tt.column {
+			t.Errorf("%s: column %d, want a column: %v", tt.name, got.Column, tt.column)
+		}
+	}
+}
+
+func TestMethodNotAllowed(t *testing.T) {
+	h := newHandler()
+	for _, tt := range []struct {
+		method, target, allow string
+	}{
+		{http.MethodGet, "/evaluate", http.MethodPost},
+		{http.MethodPost, "/operations", http.MethodGet},
+		{http.MethodPut, "/history", http.MethodGet + ", " + http.MethodDelete},
+		{http.MethodGet, "/history/replay", http.MethodPost},
+	} {
+		rec := serve(h, tt.method, tt.target, "")
+		if rec.Code != http.StatusMethodNotAllowed || rec.Header().Get("Allow") != tt.allow {
+			t.Errorf("%s %s: status %d, Allow %q, want 405 and %q", tt.method, tt.target, rec.Code, rec.Header().Get("Allow"), tt.allow)
+		}
+	}
+}
+
+func TestOperations(t *testing.T) {
+	rec := serve(newHandler(), http.MethodGet, "/operations", "")
+	if rec.Code != http.StatusOK {
+		t.Fatalf("GET /operations: status %d, want 200", rec.Code)
+	}
+	for _, op := range decodeBody[[]Operation](t, rec) {
+		if op.Name == "add" {
+			if op.Symbol != "+" || op.Arity != 2 {
+				t.Errorf("add = %+v, want the binary operator +", op)
+			}
+			return
+		}
+	}
+	t.Error("GET /operations does not list add")
+}
+
+func TestHistory(t *testing.T) {
+	h := newHandler()
+	serve(h, http.MethodPost, "/evaluate", `{"expression": "1 + 2"}`)
+	serve(h, http.MethodPost, "/evaluate", `{"expression": "1 / 0"}`)
+
+	entries := decodeBody[[]HistoryEntry](t, serve(h, http.MethodGet, "/history", ""))
+	if len(entries) != 2 {
+		t.Fatalf("GET /history returned %d entries, want 2, failed evaluations are recorded too", len(entries))
+	}
+	if entries[0].ID != 1 || entries[0].Result != "3" || entries[0].Error != "" {
+		t.Errorf("entries[0] = %+v, want ID 1 with the result 3", entries[0])
+	}
+	if entries[1].Result != "" || entries[1].Error == "" {
+		t.Errorf("entries[1] = %+v, want an error and no result", entries[1])
+	}
+
+	found := decodeBody[[]HistoryEntry](t, serve(h, http.MethodGet, "/history?q=%2F", ""))
+	if len(found) != 1 || found[0].Expression != "1 / 0" {
+		t.Errorf("GET /history?q=/ = %+v, want the entry of 1 / 0", found)
+	}
+
+	rec := serve(h, http.MethodPost, "/history/replay", `{"id": 1}`)
+	if got := decodeBody[EvaluateResponse](t, rec); rec.Code != http.StatusOK || got.Result != "3" {
+		t.Errorf("POST /history/replay 1: status %d, %+v, want 200 with the result 3", rec.Code, got)
+	}
+	if rec := serve(h, http.MethodPost, "/history/replay", `{"id": 99}`); rec.Code != http.StatusNotFound {
+		t.Errorf("POST /history/replay 99: status %d, want 404", rec.Code)
+	}
+
+	if rec := serve(h, http.MethodDelete, "/history", ""); rec.Code != http.StatusNoContent {
+		t.Errorf("DELETE /history: status %d, want 204", rec.Code)
+	}
+	if entries := decodeBody[[]HistoryEntry](t, serve(h, http.MethodGet, "/history", "")); len(entries) != 0 {
+		t.Errorf("GET /history after DELETE = %+v, want no entries", entries)
+	}
+}
+
+func TestNewServerHasTimeouts(t *testing.T) {
+	server := NewServer(":8080", app.NewService(history.NewMemory()))
+	if server.ReadHeaderTimeout <= 0 || server.ReadTimeout <= 0 {
+		t.Errorf("ReadHeaderTimeout = %v, ReadTimeout = %v, a server without timeouts can be held open by slow clients",
+			server.ReadHeaderTimeout, server.ReadTimeout)
+	}
+}
@@ -1,5 +1,59 @@
 package add
 
-func Add(a, b int) int {
+import "github.com/shashank-priyadarshi/training/calculator"
+
+// Add works for every integer and floating-point type, e.g Add(1, 2) or Add(1.5, 2.25)
+func Add[T calculator.Number](a, b T) T {
+	return a + b
+}
+
+// Checked adds a and b, but returns calculator.ErrOverflow instead of silently wrapping around
+// It works for every integer type: Checked[int8](100, 100) fails because 200 does not fit into an int8
+func Checked[T calculator.Integer](a, b T) (T, error) {
+	c := a + b
+	// Adding a positive number can only make the result bigger, and adding a negative number can only make it smaller
+	// If that is not the case, the result has wrapped around
+	if (b > 0 && c < a) || (b < 0 && c > a) {
+		return 0, calculator.NewError("add", calculator.ErrOverflow, a, b)
+	}
+	return c, nil
+}
+
+// Saturating adds a and b, stopping at the largest or the smallest value of T: Saturating[int8](100, 100) is 127
+func Saturating[T calculator.Integer](a, b T) T {
+	c, err := Checked(a, b)
+	if err == nil {
+		return c
+	}
+	lo, hi := calculator.Limits[T]()
+	if b > 0 {
+		return hi
+	}
+	return lo
+}
+
+// Wrapping adds a and b, wrapping around like the + operator does: Wrapping[int8](100, 100) is -56
+func Wrapping[T calculator.Integer](a, b T) T {
 	return a + b
 }
+
+// WithMode adds a and b, overflow decides between Checked, Saturating and Wrapping
+func WithMode[T calculator.Integer](a, b T, overflow calculator.Overflow) (T, error) {
+	switch overflow {
+	case calculator.OverflowSaturate:
+		return Saturating(a, b), nil
+	case calculator.OverflowWrap:
+		return Wrapping(a, b), nil
+	}
+	return Checked(a, b)
+}
+
+// CheckedFloat adds a and b, returning an error for NaN or infinite operands, or when the result becomes infinite
+func CheckedFloat[T calculator.Float](a, b T) (T, error) {
+	return calculator.CheckFloat("add", a+b, a, b)
+}
+
+// CheckedComplex adds complex numbers like CheckedFloat, the real and the imaginary parts are checked
+func CheckedComplex[T calculator.Complex](a, b T) (T, error) {
+	return calculator.CheckComplex("add", a+b, a, b)
+}
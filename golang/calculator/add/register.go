@@ -0,0 +1,33 @@
+package add
+
+import "github.com/shashank-priyadarshi/training/calculator"
+
+func init() {
+	calculator.Register(calculator.Func{
+		Spec: calculator.Info{
+			Name:        "add",
+			Symbol:      "+",
+			Arity:       2,
+			Precedence:  calculator.PrecAdditive,
+			Description: "a + b adds b to a",
+		},
+		Int:     calculator.Binary(WithMode[int64]),
+		Uint:    calculator.Binary(WithMode[uint64]),
+		Float:   calculator.FloatBinary(CheckedFloat[float64]),
+		Complex: calculator.ComplexBinary(CheckedComplex[complex128]),
+	})
+
+	calculator.Register(calculator.Func{
+		Spec: calculator.Info{
+			Name:        "plus",
+			Symbol:      "+",
+			Arity:       1,
+			Precedence:  calculator.PrecUnary,
+			Description: "+a is a itself",
+		},
+		Int:     func(_ calculator.Overflow, args ...int64) (int64, error) { return args[0], nil },
+		Uint:    func(_ calculator.Overflow, args ...uint64) (uint64, error) { return args[0], nil },
+		Float:   func(args ...float64) (float64, error) { return args[0], nil },
+		Complex: func(args ...complex128) (complex128, error) { return args[0], nil },
+	})
+}
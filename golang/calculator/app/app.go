@@ -0,0 +1,178 @@
+// Package app is the core of the calculator application, following the hexagonal (ports and adapters) architecture
+//
+// The domain is made of the calculator packages: add, subtract, multiply, divide, scientific and expr
+// The application core below uses the domain to evaluate expressions, and knows nothing about how it is called
+// Adapters drive the core through the Calculator port:
+//
+//	calculator/adapter/cli      the command line: flags, the demo and the interactive calculator
+//	calculator/adapter/httpapi  HTTP with JSON: POST /evaluate and GET /operations
+//
+// The core drives the storage adapters through the HistoryRepository port, see calculator/adapter/history
+//
+// The core never imports an adapter, so a new adapter, e.g gRPC, can be added without changing the core
+// Adapters can be tested with a fake Calculator, and the core can be tested without a terminal or a network
+package app
+
+import (
+	"fmt"
+	"time"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+	"github.com/shashank-priyadarshi/training/calculator/expr"
+)
+
+// Calculator is the port the adapters use to drive the application
+type Calculator interface {
+	// Evaluate evaluates an expression with the mode, or with expr.DefaultMode when mode is empty
+	Evaluate(expression, mode string) (Result, error)
+	// Operations returns the operators and functions of the calculator
+	Operations() []calculator.Info
+	// Modes returns the names of the modes, see expr.Modes
+	Modes() []string
+
+	// History returns the recorded evaluations whose expression or result contains query, or all of them when query is empty
+	History(query string) ([]HistoryEntry, error)
+	// Replay evaluates the expression of a history entry again, with the same mode
+	// RPN lines fail with ErrNotReplayable
+	Replay(id int) (Result, error)
+	// ClearHistory removes all recorded evaluations
+	ClearHistory() error
+
+	// NewSession returns an evaluator for the mode, or for expr.DefaultMode when mode is empty, that keeps variables
+	// and ans between statements, for the interactive calculator
+	// Statements evaluated with its Eval method are recorded in the history like with Evaluate, and so are
+	// the lines of its RPN method, with the top of the stack as their result
+	NewSession(mode string) (expr.Evaluator, error)
+}
+
+// Result is an evaluated expression
+type Result struct {
+	Expression string
+	Mode       string
+	Value      string // The value formatted by the mode, e.g 0.5 or 1/2
+}
+
+// Service implements Calculator with the calculator packages
+type Service struct {
+	history HistoryRepository
+	now     func() time.Time // Time of the history entries
+}
+
+// NewService returns the Calculator used by the adapters, every evaluation is recorded in history
+func NewService(history HistoryRepository) *Service {
+	return &Service{history: history, now: time.Now}
+}
+
+// Evaluate evaluates the expression with a new session, so variables and ans are not shared between calls
+// Errors are the ones of the expr package, e.g *expr.SyntaxError, *expr.EvalError or expr.ErrUnknownMode
+// The evaluation is recorded in the history even when it fails
+func (s *Service) Evaluate(expression, mode string) (Result, error) {
+	if mode == "" {
+		mode = expr.DefaultMode
+	}
+
+	result, err := s.evaluate(expression, mode)
+	if recordErr := s.record(HistoryEntry{Expression: expression, Mode: mode, Result: result.Value}, err); recordErr != nil && err == nil {
+		return result, recordErr
+	}
+	return result, err
+}
+
+func (s *Service) evaluate(expression, mode string) (Result, error) {
+	evaluator, err := expr.NewEvaluator(mode)
+	if err != nil {
+		return Result{}, err
+	}
+
+	_, v, err := evaluator.Eval(expression)
+	if err != nil {
+		return Result{}, err
+	}
+	return Result{Expression: expression, Mode: mode, Value: v}, nil
+}
+
+func (s *Service) Operations() []calculator.Info {
+	ops := calculator.Operations()
+	infos := make([]calculator.Info, len(ops))
+	for i, op := range ops {
+		infos[i] = op.Info()
+	}
+	return infos
+}
+
+func (s *Service) Modes() []string {
+	return expr.Modes()
+}
+
+func (s *Service) History(query string) ([]HistoryEntry, error) {
+	if query == "" {
+		return s.history.List()
+	}
+	return s.history.Search(query)
+}
+
+func (s *Service) Replay(id int) (Result, error) {
+	entry, err := s.history.Get(id)
+	if err != nil {
+		return Result{}, err
+	}
+	if entry.RPN {
+		return Result{}, fmt.Errorf("history entry %d: %w", id, ErrNotReplayable)
+	}
+	return s.Evaluate(entry.Expression, entry.Mode)
+}
+
+func (s *Service) ClearHistory() error {
+	return s.history.Clear()
+}
+
+// NewSession returns a session that records every statement of Eval and every line of RPN
+func (s *Service) NewSession(mode string) (expr.Evaluator, error) {
+	if mode == "" {
+		mode = expr.DefaultMode
+	}
+	evaluator, err := expr.NewEvaluator(mode)
+	if err != nil {
+		return nil, err
+	}
+	return &session{Evaluator: evaluator, service: s}, nil
+}
+
+// session is an expr.Evaluator that records its statements, the other methods come from the embedded Evaluator
+type session struct {
+	expr.Evaluator
+	service *Service
+}
+
+func (s *session) Eval(src string) (expr.Statement, string, error) {
+	stmt, v, err := s.Evaluator.Eval(src)
+	if recordErr := s.service.record(HistoryEntry{Expression: src, Mode: s.Mode(), Result: v}, err); recordErr != nil && err == nil {
+		return stmt, v, recordErr
+	}
+	return stmt, v, err
+}
+
+// RPN records the line with the top of the stack, the line alone does not tell the result, so it is marked as RPN
+func (s *session) RPN(src string) ([]string, error) {
+	stack, err := s.Evaluator.RPN(src)
+	var top string
+	if len(stack) > 0 {
+		top = stack[len(stack)-1]
+	}
+	if recordErr := s.service.record(HistoryEntry{Expression: src, Mode: s.Mode(), Result: top, RPN: true}, err); recordErr != nil && err == nil {
+		return stack, recordErr
+	}
+	return stack, err
+}
+
+// record adds an evaluation to the history with the current time, the error of the evaluation is recorded instead of the result
+func (s *Service) record(entry HistoryEntry, err error) error {
+	entry.Time = s.now()
+	if err != nil {
+		entry.Result, entry.Error = "", err.Error()
+	}
+	if _, err := s.history.Add(entry); err != nil {
+		return fmt.Errorf("recording history: %w", err)
+	}
+	return nil
+}
@@ -0,0 +1,80 @@
+package app_test
+
+import (
+	"errors"
+	"testing"
+
+	"github.com/shashank-priyadarshi/training/calculator/adapter/history"
+	"github.com/shashank-priyadarshi/training/calculator/app"
+	"github.com/shashank-priyadarshi/training/calculator/expr"
+)
+
+func TestSessionIsRecorded(t *testing.T) {
+	store := history.NewMemory()
+	session, err := app.NewService(store).NewSession("")
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	// Variables are kept between statements, which is why the interactive calculator needs a session
+	for _, line := range []string{"let x = 3", "x * 2", "1 / 0"} {
+		_, _, _ = session.Eval(line)
+	}
+	// An RPN line is recorded with the top of the stack, 4 * multiplies the 3 left by the line before
+	for _, line := range []string{"1 2 +", "4 *"} {
+		if _, err := session.RPN(line); err != nil {
+			t.Fatal(err)
+		}
+	}
+	if _, err := session.RPN("+"); !errors.Is(err, expr.ErrStackUnderflow) {
+		t.Fatalf("RPN(+) err = %v, want ErrStackUnderflow", err)
+	}
+
+	entries, err := store.List()
+	if err != nil {
+		t.Fatal(err)
+	}
+	want := []app.HistoryEntry{
+		{Expression: "let x = 3", Mode: "int", Result: "3"},
+		{Expression: "x * 2", Mode: "int", Result: "6"},
+		{Expression: "1 / 0", Mode: "int"},
+		{Expression: "1 2 +", Mode: "int", Result: "3", RPN: true},
+		{Expression: "4 *", Mode: "int", Result: "12", RPN: true},
+		{Expression: "+", Mode: "int", RPN: true},
+	}
+	if len(entries) != len(want) {
+		t.Fatalf("recorded %d entries, want %d: %+v", len(entries), len(want), entries)
+	}
+	for i, w := range want {
+		got := entries[i]
+		if got.Expression != w.Expression || got.Mode != w.Mode || got.Result != w.Result || got.RPN != w.RPN || (got.Error != "") != (w.Result == "") {
+			t.Errorf("entries[%d] = %+v, want %+v", i, got, w)
+		}
+	}
+}
+
+func TestReplay(t *testing.T) {
+	store := history.NewMemory()
+	service := app.NewService(store)
+	if _, err := service.Evaluate("1 + 2", "uint8"); err != nil {
+		t.Fatal(err)
+	}
+	session, err := service.NewSession("")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if _, err := session.RPN("1 2 +"); err != nil {
+		t.Fatal(err)
+	}
+
+	if got, err := service.Replay(1); err != nil || got != (app.Result{Expression: "1 + 2", Mode: "uint8", Value: "3"}) {
+		t.Errorf("Replay(1) = %+v, %v, want 3 in uint8 mode", got, err)
+	}
+	// The RPN line alone does not tell what was on the stack
+	if _, err := service.Replay(2); !errors.Is(err, app.ErrNotReplayable) {
+		t.Errorf("Replay of an RPN line err = %v, want ErrNotReplayable", err)
+	}
+	if _, err := service.Replay(9); !errors.Is(err, app.ErrNotFound) {
+		t.Errorf("Replay(9) err = %v, want ErrNotFound", err)
+	}
+}
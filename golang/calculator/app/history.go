@@ -0,0 +1,38 @@
+package app
+
+import (
+	"errors"
+	"time"
+)
+
+// ErrNotFound is returned when a history entry does not exist
+var ErrNotFound = errors.New("history entry not found")
+
+// ErrNotReplayable is returned when replaying an RPN line, its result depends on the values that were already on the stack
+var ErrNotReplayable = errors.New("RPN history entries cannot be replayed")
+
+// HistoryEntry is an evaluation recorded in the history
+type HistoryEntry struct {
+	ID         int // Assigned by the repository, counting from 1
+	Expression string
+	Mode       string
+	Result     string // Empty when the evaluation failed
+	Error      string // Empty when the evaluation succeeded
+	RPN        bool   // The expression is an RPN line and the result is the top of the stack, see ErrNotReplayable
+	Time       time.Time
+}
+
+// HistoryRepository is the port through which the core stores the history, it is implemented by the storage adapters
+// in calculator/adapter/history, one keeps the history in memory and one in a file
+type HistoryRepository interface {
+	// Add stores the entry with a new ID and returns it
+	Add(entry HistoryEntry) (HistoryEntry, error)
+	// List returns all entries, oldest first
+	List() ([]HistoryEntry, error)
+	// Get returns the entry with the ID, or ErrNotFound
+	Get(id int) (HistoryEntry, error)
+	// Search returns the entries whose expression or result contains query, oldest first
+	Search(query string) ([]HistoryEntry, error)
+	// Clear removes all entries
+	Clear() error
+}
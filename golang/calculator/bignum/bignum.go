@@ -0,0 +1,237 @@
+// Package bignum evaluates expressions with arbitrary-precision numbers from math/big
+// An int stops at 9223372036854775807 on 64-bit systems, a big.Int grows as long as there is memory
+//
+// Int, Rat and Float are arithmetics for the expr package, selected with the bigint, bigrat and bigfloat modes
+package bignum
+
+import (
+	"fmt"
+	"math/big"
+	"strings"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+)
+
+// FloatPrec is the number of bits in the mantissa of a Float, float64 has 53
+const FloatPrec = 256
+
+// MaxShift is the largest shift count of << and >> in bigint mode, 1 << MaxShift already has 20 thousand digits
+// A big.Int never overflows, but 1 << 1e18 would need more memory than any computer has
+const MaxShift = 1 << 16
+
+// Int evaluates expressions with big.Int, results never overflow
+// Division truncates towards 0 like the / operator on ints: 7 / 2 is 3
+type Int struct{}
+
+func (Int) Name() string {
+	return "bigint"
+}
+
+// Literal reads decimal integers and integers with a base prefix like 0xff
+func (Int) Literal(text string) (*big.Int, error) {
+	base := 10
+	if prefixed(text) {
+		base = 0 // SetString reads the prefix
+	} else if strings.ContainsAny(text, ".eE") {
+		return nil, fmt.Errorf("%w: %s is not an integer, switch to bigrat or bigfloat mode for fractions", calculator.ErrInvalidOperand, text)
+	}
+	v, ok := new(big.Int).SetString(text, base)
+	if !ok {
+		return nil, fmt.Errorf("%w: %s is not a number", calculator.ErrInvalidOperand, text)
+	}
+	return v, nil
+}
+
+// Apply always returns a new big.Int, the operands are never changed
+func (Int) Apply(op string, args ...*big.Int) (*big.Int, error) {
+	if len(args) == 1 {
+		switch op {
+		case "plus":
+			return args[0], nil
+		case "negate":
+			return new(big.Int).Neg(args[0]), nil
+		case "complement":
+			return new(big.Int).Not(args[0]), nil
+		}
+		return nil, unsupported(op, len(args))
+	}
+
+	x, y := args[0], args[1]
+	switch op {
+	case "add":
+		return new(big.Int).Add(x, y), nil
+	case "subtract":
+		return new(big.Int).Sub(x, y), nil
+	case "multiply":
+		return new(big.Int).Mul(x, y), nil
+	case "divide":
+		if y.Sign() == 0 {
+			return nil, calculator.NewError("divide", calculator.ErrDivisionByZero, x, y)
+		}
+		return new(big.Int).Quo(x, y), nil
+	// Bitwise operators work as if negative numbers had an infinite number of 1 bits on the left, in two's complement
+	case "and":
+		return new(big.Int).And(x, y), nil
+	case "or":
+		return new(big.Int).Or(x, y), nil
+	case "xor":
+		return new(big.Int).Xor(x, y), nil
+	case "andnot":
+		return new(big.Int).AndNot(x, y), nil
+	case "shl", "shr":
+		if y.Sign() < 0 || y.Cmp(big.NewInt(MaxShift)) > 0 {
+			return nil, calculator.NewError(op, fmt.Errorf("%w: the shift count must be between 0 and %d", calculator.ErrInvalidOperand, MaxShift), x, y)
+		}
+		if op == "shl" {
+			return new(big.Int).Lsh(x, uint(y.Uint64())), nil
+		}
+		return new(big.Int).Rsh(x, uint(y.Uint64())), nil
+	}
+	return nil, unsupported(op, len(args))
+}
+
+func (Int) Format(v *big.Int) string {
+	return v.String()
+}
+
+// FormatBase writes v in base 2 to 36, with the prefix of a Go literal for bases 2, 8 and 16, like expr.Fixed does
+func (Int) FormatBase(v *big.Int, base int) string {
+	prefix := map[int]string{2: "0b", 8: "0o", 16: "0x"}[base]
+	if v.Sign() < 0 {
+		return "-" + prefix + new(big.Int).Neg(v).Text(base)
+	}
+	return prefix + v.Text(base)
+}
+
+// Rat evaluates expressions with big.Rat, fractions are exact: 1 / 3 is 1/3 and 1 / 3 * 3 is 1
+type Rat struct{}
+
+func (Rat) Name() string {
+	return "bigrat"
+}
+
+// Literal accepts fractions written as decimals, 1.25 is 5/4
+func (Rat) Literal(text string) (*big.Rat, error) {
+	v, ok := new(big.Rat).SetString(text)
+	if !ok {
+		return nil, fmt.Errorf("%w: %s is not a number", calculator.ErrInvalidOperand, text)
+	}
+	return v, nil
+}
+
+// Apply always returns a new big.Rat, the operands are never changed
+func (Rat) Apply(op string, args ...*big.Rat) (*big.Rat, error) {
+	if len(args) == 1 {
+		switch op {
+		case "plus":
+			return args[0], nil
+		case "negate":
+			return new(big.Rat).Neg(args[0]), nil
+		}
+		return nil, unsupported(op, len(args))
+	}
+
+	x, y := args[0], args[1]
+	switch op {
+	case "add":
+		return new(big.Rat).Add(x, y), nil
+	case "subtract":
+		return new(big.Rat).Sub(x, y), nil
+	case "multiply":
+		return new(big.Rat).Mul(x, y), nil
+	case "divide":
+		if y.Sign() == 0 {
+			return nil, calculator.NewError("divide", calculator.ErrDivisionByZero, x, y)
+		}
+		return new(big.Rat).Quo(x, y), nil
+	}
+	return nil, unsupported(op, len(args))
+}
+
+// Format writes whole numbers without a denominator: 2 instead of 2/1
+func (Rat) Format(v *big.Rat) string {
+	return v.RatString()
+}
+
+// Float evaluates expressions with big.Float using FloatPrec bits, 1 / 3 has far more digits than with float64
+type Float struct{}
+
+func (Float) Name() string {
+	return "bigfloat"
+}
+
+func (Float) Literal(text string) (*big.Float, error) {
+	v, _, err := big.ParseFloat(text, 0, FloatPrec, big.ToNearestEven) // Base 0 reads prefixes like 0xff
+	if err != nil {
+		return nil, fmt.Errorf("%w: %s: %v", calculator.ErrInvalidOperand, text, err)
+	}
+	return v, nil
+}
+
+// Apply always returns a new big.Float, the operands are never changed
+func (Float) Apply(op string, args ...*big.Float) (*big.Float, error) {
+	if len(args) == 1 {
+		switch op {
+		case "plus":
+			return args[0], nil
+		case "negate":
+			return newFloat().Neg(args[0]), nil
+		}
+		return nil, unsupported(op, len(args))
+	}
+
+	x, y := args[0], args[1]
+	if undefined(op, x, y) {
+		return nil, calculator.NewError(op, calculator.ErrInvalidOperand, x, y)
+	}
+	switch op {
+	case "add":
+		return newFloat().Add(x, y), nil
+	case "subtract":
+		return newFloat().Sub(x, y), nil
+	case "multiply":
+		return newFloat().Mul(x, y), nil
+	case "divide":
+		if y.Sign() == 0 {
+			return nil, calculator.NewError("divide", calculator.ErrDivisionByZero, x, y)
+		}
+		return newFloat().Quo(x, y), nil
+	}
+	return nil, unsupported(op, len(args))
+}
+
+// undefined reports whether op has no result for x and y, e.g Inf - Inf
+// A big.Float overflows to Inf, e.g after squaring a number long enough, but it has no NaN: big.Float panics with
+// big.ErrNaN instead, so these operands are rejected before the operation is called
+func undefined(op string, x, y *big.Float) bool {
+	switch op {
+	case "add":
+		return x.IsInf() && y.IsInf() && x.Signbit() != y.Signbit()
+	case "subtract":
+		return x.IsInf() && y.IsInf() && x.Signbit() == y.Signbit()
+	case "multiply":
+		return x.IsInf() && y.Sign() == 0 || x.Sign() == 0 && y.IsInf()
+	case "divide":
+		return x.IsInf() && y.IsInf() // 0 / 0 is a division by zero
+	}
+	return false
+}
+
+// Format writes up to 50 significant digits
+func (Float) Format(v *big.Float) string {
+	return v.Text('g', 50)
+}
+
+func newFloat() *big.Float {
+	return new(big.Float).SetPrec(FloatPrec)
+}
+
+// prefixed reports whether text is an integer with a base prefix: 0x, 0o or 0b
+func prefixed(text string) bool {
+	text = strings.TrimPrefix(text, "-")
+	return len(text) > 2 && text[0] == '0' && strings.ContainsRune("xXoObB", rune(text[1]))
+}
+
+func unsupported(op string, arity int) error {
+	return fmt.Errorf("%s with %d operands is not available in the big modes", op, arity)
+}
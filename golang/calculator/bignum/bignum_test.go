@@ -0,0 +1,46 @@
+package bignum
+
+import (
+	"errors"
+	"math/big"
+	"testing"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+)
+
+func TestFloatWithoutResultIsAnError(t *testing.T) {
+	inf, negInf, zero, one := new(big.Float).SetInf(false), new(big.Float).SetInf(true), new(big.Float), big.NewFloat(1)
+	for _, tt := range []struct {
+		op   string
+		x, y *big.Float
+	}{
+		{"add", inf, negInf},
+		{"add", negInf, inf},
+		{"subtract", inf, inf},
+		{"subtract", negInf, negInf},
+		{"multiply", zero, inf},
+		{"multiply", negInf, zero},
+		{"divide", inf, negInf},
+	} {
+		// big.Float would panic with big.ErrNaN, Apply must return an error instead
+		_, err := Float{}.Apply(tt.op, tt.x, tt.y)
+		if !errors.Is(err, calculator.ErrInvalidOperand) {
+			t.Errorf("Apply(%s, %v, %v) err = %v, want ErrInvalidOperand", tt.op, tt.x, tt.y, err)
+		}
+	}
+
+	// Infinity itself is a result
+	for _, tt := range []struct {
+		op   string
+		x, y *big.Float
+	}{
+		{"add", inf, one},
+		{"subtract", inf, negInf},
+		{"multiply", inf, negInf},
+		{"divide", one, inf},
+	} {
+		if _, err := (Float{}).Apply(tt.op, tt.x, tt.y); err != nil {
+			t.Errorf("Apply(%s, %v, %v) err = %v, want a result", tt.op, tt.x, tt.y, err)
+		}
+	}
+}
@@ -0,0 +1,90 @@
+// Package bitwise implements the bitwise operators of Go on integers: & | ^ &^ << >> and the unary ^
+// They work on the bits of a number instead of its value, 12 & 10 is 8 because 1100 & 1010 is 1000
+//
+// Negative numbers are stored in two's complement: -x is ^x + 1, all bits flipped plus one
+// So -1 has every bit set, and the sign bit, the leftmost one, is set for every negative number, see Pattern
+package bitwise
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+)
+
+// And returns the bits set in both a and b: 12 & 10 is 8
+func And[T calculator.Integer](a, b T) T {
+	return a & b
+}
+
+// Or returns the bits set in a or b: 12 | 10 is 14
+func Or[T calculator.Integer](a, b T) T {
+	return a | b
+}
+
+// Xor returns the bits set in a or b but not in both: 12 ^ 10 is 6
+func Xor[T calculator.Integer](a, b T) T {
+	return a ^ b
+}
+
+// AndNot returns the bits of a that are not set in b, it clears the bits of b: 12 &^ 10 is 4
+func AndNot[T calculator.Integer](a, b T) T {
+	return a &^ b
+}
+
+// Complement flips every bit of a: ^int8(5) is -6, ^uint8(5) is 250
+func Complement[T calculator.Integer](a T) T {
+	return ^a
+}
+
+// ShiftLeft moves the bits of a n places to the left, which multiplies a by 2^n: 3 << 4 is 48
+// Bits that are shifted out are lost, overflow decides what happens then, like for multiply.WithMode
+func ShiftLeft[T calculator.Integer](a, n T, overflow calculator.Overflow) (T, error) {
+	if n < 0 {
+		return 0, calculator.NewError("shl", calculator.ErrInvalidOperand, a, n)
+	}
+
+	// Shifting back gives a again only when no bits were lost, Go shifts by more than the size of T give 0
+	result := a << n
+	if result>>n == a {
+		return result, nil
+	}
+	switch overflow {
+	case calculator.OverflowWrap:
+		return result, nil
+	case calculator.OverflowSaturate:
+		lo, hi := calculator.Limits[T]()
+		if a < 0 {
+			return lo, nil
+		}
+		return hi, nil
+	}
+	return 0, calculator.NewError("shl", calculator.ErrOverflow, a, n)
+}
+
+// ShiftRight moves the bits of a n places to the right, which divides a by 2^n rounding down: 50 >> 4 is 3
+// Signed integers keep their sign, the sign bit is copied into the bits on the left: -8 >> 1 is -4
+func ShiftRight[T calculator.Integer](a, n T) (T, error) {
+	if n < 0 {
+		return 0, calculator.NewError("shr", calculator.ErrInvalidOperand, a, n)
+	}
+	return a >> n, nil
+}
+
+// Pattern returns the bits of v as they are stored in memory, in groups of 8: Pattern(int8(-1)) is 11111111
+// Negative numbers show their two's complement, Pattern(int8(-128)) is 10000000 and Pattern(int8(127)) is 01111111
+func Pattern[T calculator.Integer](v T) string {
+	bits := calculator.Bits[T]()
+	// uint64(v) repeats the sign bit of a negative v on the left, only the bits of T are kept
+	u := uint64(v)
+	if bits < 64 {
+		u &= uint64(1)<<bits - 1
+	}
+	s := fmt.Sprintf("%0*b", bits, u)
+
+	groups := make([]string, 0, bits/8)
+	for i := 0; i < len(s); i += 8 {
+		groups = append(groups, s[i:i+8])
+	}
+	return strings.Join(groups, " ")
+}
@@ -0,0 +1,58 @@
+package bitwise
+
+import (
+	"errors"
+	"strings"
+	"testing"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+)
+
+func TestShiftLeft(t *testing.T) {
+	for _, tt := range []struct {
+		a, n     int8
+		overflow calculator.Overflow
+		want     int8
+		err      error
+	}{
+		{3, 4, calculator.OverflowError, 48, nil},
+		{-1, 7, calculator.OverflowError, -128, nil},
+		{0, 100, calculator.OverflowError, 0, nil},
+		{64, 1, calculator.OverflowError, 0, calculator.ErrOverflow},
+		{1, -1, calculator.OverflowError, 0, calculator.ErrInvalidOperand},
+		{64, 1, calculator.OverflowWrap, -128, nil},
+		// Saturating stops at the limit with the sign of a
+		{64, 1, calculator.OverflowSaturate, 127, nil},
+		{-65, 1, calculator.OverflowSaturate, -128, nil},
+		// Shifting by the size of the type or more loses every bit
+		{1, 8, calculator.OverflowSaturate, 127, nil},
+		{-1, 8, calculator.OverflowSaturate, -128, nil},
+	} {
+		got, err := ShiftLeft(tt.a, tt.n, tt.overflow)
+		if got != tt.want || !errors.Is(err, tt.err) {
+			t.Errorf("ShiftLeft(%d, %d, %v) = %d, %v, want %d, %v", tt.a, tt.n, tt.overflow, got, err, tt.want, tt.err)
+		}
+	}
+
+	if got, err := ShiftLeft[uint8](200, 1, calculator.OverflowSaturate); got != 255 || err != nil {
+		t.Errorf("ShiftLeft(uint8(200), 1, saturate) = %d, %v, want 255", got, err)
+	}
+}
+
+func TestPattern(t *testing.T) {
+	for _, tt := range []struct {
+		got, want string
+	}{
+		{Pattern(int8(-1)), "11111111"},
+		{Pattern(int8(-128)), "10000000"},
+		{Pattern(int8(127)), "01111111"},
+		{Pattern(uint8(5)), "00000101"},
+		{Pattern(int16(-2)), "11111111 11111110"},
+		{Pattern(uint16(256)), "00000001 00000000"},
+		{Pattern(int64(-1)), strings.TrimSpace(strings.Repeat("11111111 ", 8))},
+	} {
+		if tt.got != tt.want {
+			t.Errorf("Pattern = %s, want %s", tt.got, tt.want)
+		}
+	}
+}
@@ -0,0 +1,57 @@
+package bitwise
+
+import "github.com/shashank-priyadarshi/training/calculator"
+
+// The operators have the precedence they have in Go: & &^ << >> like *, and | ^ like +, so 1 | 2 & 3 is 1 | (2 & 3)
+// They only work with integers, floats and complex numbers have no bits to work with in the calculator
+func init() {
+	register("and", "&", calculator.PrecMultiplicative, "a & b has the bits set in both a and b, 12 & 10 is 8",
+		binary(And[int64]), binary(And[uint64]))
+	register("andnot", "&^", calculator.PrecMultiplicative, "a &^ b clears the bits of b in a, 12 &^ 10 is 4",
+		binary(AndNot[int64]), binary(AndNot[uint64]))
+	register("shl", "<<", calculator.PrecMultiplicative, "a << n shifts the bits of a n places to the left, 3 << 4 is 48",
+		calculator.Binary(ShiftLeft[int64]), calculator.Binary(ShiftLeft[uint64]))
+	register("shr", ">>", calculator.PrecMultiplicative, "a >> n shifts the bits of a n places to the right, 50 >> 4 is 3",
+		calculator.CheckedBinary(ShiftRight[int64]), calculator.CheckedBinary(ShiftRight[uint64]))
+	register("or", "|", calculator.PrecAdditive, "a | b has the bits set in a or b, 12 | 10 is 14",
+		binary(Or[int64]), binary(Or[uint64]))
+	register("xor", "^", calculator.PrecAdditive, "a ^ b has the bits set in a or b but not both, 12 ^ 10 is 6, it is not a power",
+		binary(Xor[int64]), binary(Xor[uint64]))
+
+	calculator.Register(calculator.Func{
+		Spec: calculator.Info{
+			Name:        "complement",
+			Symbol:      "^",
+			Arity:       1,
+			Precedence:  calculator.PrecUnary,
+			Description: "^a flips every bit of a, ^5 is -6",
+		},
+		Int:  calculator.CheckedUnary(func(a int64) (int64, error) { return Complement(a), nil }),
+		Uint: calculator.CheckedUnary(func(a uint64) (uint64, error) { return Complement(a), nil }),
+	})
+}
+
+func register(
+	name, symbol string,
+	precedence int,
+	description string,
+	i func(calculator.Overflow, ...int64) (int64, error),
+	u func(calculator.Overflow, ...uint64) (uint64, error),
+) {
+	calculator.Register(calculator.Func{
+		Spec: calculator.Info{
+			Name:        name,
+			Symbol:      symbol,
+			Arity:       2,
+			Precedence:  precedence,
+			Description: description,
+		},
+		Int:  i,
+		Uint: u,
+	})
+}
+
+// binary adapts an operator that cannot fail, like And[int64], to the Int and Uint functions of calculator.Func
+func binary[T calculator.Integer](f func(a, b T) T) func(calculator.Overflow, ...T) (T, error) {
+	return func(_ calculator.Overflow, args ...T) (T, error) { return f(args[0], args[1]), nil }
+}
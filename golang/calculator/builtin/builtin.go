@@ -0,0 +1,14 @@
+// Package builtin registers the operations that come with the calculator
+// Importing it for its side effects is enough: import _ "github.com/shashank-priyadarshi/training/calculator/builtin"
+// A new operation is a package that registers itself in its init function, added to the imports below
+package builtin
+
+import (
+	_ "github.com/shashank-priyadarshi/training/calculator/add"
+	_ "github.com/shashank-priyadarshi/training/calculator/bitwise"
+	_ "github.com/shashank-priyadarshi/training/calculator/complexnum"
+	_ "github.com/shashank-priyadarshi/training/calculator/divide"
+	_ "github.com/shashank-priyadarshi/training/calculator/multiply"
+	_ "github.com/shashank-priyadarshi/training/calculator/scientific"
+	_ "github.com/shashank-priyadarshi/training/calculator/subtract"
+)
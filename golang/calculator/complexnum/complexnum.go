@@ -0,0 +1,46 @@
+// Package complexnum implements the functions of complex numbers: magnitude, phase and conjugate
+// Go has two complex types, complex64 and complex128, made of two float32s or two float64s: the real and the imaginary part
+// 3+4i has the real part 3 and the imaginary part 4, and i*i is -1
+package complexnum
+
+import (
+	"math"
+	"math/cmplx"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+)
+
+// Abs returns the magnitude of z, its distance from 0: Abs(3+4i) is 5
+func Abs[T calculator.Complex](z T) float64 {
+	return cmplx.Abs(complex128(z))
+}
+
+// Phase returns the angle of z in radians, between -Pi and Pi: Phase(1i) is Pi/2
+func Phase[T calculator.Complex](z T) float64 {
+	return cmplx.Phase(complex128(z))
+}
+
+// Conj returns the conjugate of z, which has the opposite imaginary part: Conj(3+4i) is 3-4i
+func Conj[T calculator.Complex](z T) T {
+	return T(cmplx.Conj(complex128(z)))
+}
+
+// Real returns the real part of z: Real(3+4i) is 3
+func Real[T calculator.Complex](z T) float64 {
+	return real(complex128(z))
+}
+
+// Imag returns the imaginary part of z: Imag(3+4i) is 4
+func Imag[T calculator.Complex](z T) float64 {
+	return imag(complex128(z))
+}
+
+// Polar returns z as its magnitude and its phase: Polar(1i) is 1, Pi/2
+func Polar[T calculator.Complex](z T) (r, theta float64) {
+	return Abs(z), Phase(z)
+}
+
+// absFloat is Abs for the float mode, where abs(-2) is 2
+func absFloat(x float64) (float64, error) {
+	return math.Abs(x), nil
+}
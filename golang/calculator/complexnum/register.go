@@ -0,0 +1,50 @@
+package complexnum
+
+import (
+	"github.com/shashank-priyadarshi/training/calculator"
+	"github.com/shashank-priyadarshi/training/calculator/subtract"
+)
+
+func init() {
+	calculator.Register(calculator.Func{
+		Spec: calculator.Info{
+			Name:        "abs",
+			Arity:       1,
+			Precedence:  calculator.PrecFunction,
+			Description: "abs(x) is the magnitude of x, abs(-2) is 2 and abs(3+4i) is 5",
+		},
+		Int: calculator.Unary(func(a int64, overflow calculator.Overflow) (int64, error) {
+			if a < 0 {
+				return subtract.WithMode(0, a, overflow)
+			}
+			return a, nil
+		}),
+		Uint:    func(_ calculator.Overflow, args ...uint64) (uint64, error) { return args[0], nil },
+		Float:   calculator.FloatUnary(absFloat),
+		Complex: real128(Abs[complex128]),
+	})
+
+	register("phase", "phase(z) is the angle of z in radians, phase(1i) is Pi/2", real128(Phase[complex128]))
+	register("conj", "conj(z) is the conjugate of z, conj(3+4i) is 3-4i",
+		calculator.ComplexUnary(func(z complex128) (complex128, error) { return Conj(z), nil }))
+	register("real", "real(z) is the real part of z, real(3+4i) is 3", real128(Real[complex128]))
+	register("imag", "imag(z) is the imaginary part of z, imag(3+4i) is 4", real128(Imag[complex128]))
+}
+
+// register registers a function of complex numbers with one operand
+func register(name, description string, f func(...complex128) (complex128, error)) {
+	calculator.Register(calculator.Func{
+		Spec: calculator.Info{
+			Name:        name,
+			Arity:       1,
+			Precedence:  calculator.PrecFunction,
+			Description: description,
+		},
+		Complex: f,
+	})
+}
+
+// real128 adapts a function returning a float64, like Abs, to a function returning a complex number with no imaginary part
+func real128(f func(z complex128) float64) func(...complex128) (complex128, error) {
+	return func(args ...complex128) (complex128, error) { return complex(f(args[0]), 0), nil }
+}
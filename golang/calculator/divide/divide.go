@@ -1,11 +1,102 @@
 package divide
 
-import "fmt"
+import (
+	"fmt"
 
-func Divide(a, b int) int {
+	"github.com/shashank-priyadarshi/training/calculator"
+)
+
+// Divide works for every integer and floating-point type, and the type decides what kind of division happens:
+// Divide(1, 2) divides two ints and gives 0, because integer division drops the fraction
+// Divide(1.0, 2.0) divides two float64s and gives 0.5
+//
+// Divide prints a message and returns 0 when b is 0, so the caller cannot tell a failure from a real 0
+// Prefer Checked or CheckedFloat, which return an error instead
+func Divide[T calculator.Number](a, b T) T {
 	if b == 0 {
 		fmt.Println("Dividing by 0 not allowed") // Error return & error handling
 		return 0
 	}
 	return a / b
 }
+
+// Checked divides a by b, returning calculator.ErrDivisionByZero when b is 0
+// The smallest signed value divided by -1 does not fit into T, so Checked[int8](-128, -1) returns calculator.ErrOverflow
+func Checked[T calculator.Integer](a, b T) (T, error) {
+	if b == 0 {
+		return 0, calculator.NewError("divide", calculator.ErrDivisionByZero, a, b)
+	}
+	if minByMinusOne(a, b) {
+		return 0, calculator.NewError("divide", calculator.ErrOverflow, a, b)
+	}
+	return a / b, nil
+}
+
+// minByMinusOne reports whether a is the smallest value of a signed T and b is -1
+func minByMinusOne[T calculator.Integer](a, b T) bool {
+	lo, _ := calculator.Limits[T]()
+	return calculator.IsSigned[T]() && a == lo && b == ^T(0) // All bits set is -1 for signed types
+}
+
+// Saturating divides a by b, stopping at the largest value of T: Saturating[int8](-128, -1) is 127
+// Dividing by 0 has no result to stop at, so it still returns calculator.ErrDivisionByZero
+func Saturating[T calculator.Integer](a, b T) (T, error) {
+	if minByMinusOne(a, b) {
+		_, hi := calculator.Limits[T]()
+		return hi, nil
+	}
+	return Checked(a, b)
+}
+
+// Wrapping divides a by b, wrapping around like the / operator does: Wrapping[int8](-128, -1) is -128
+// Dividing by 0 still returns calculator.ErrDivisionByZero
+func Wrapping[T calculator.Integer](a, b T) (T, error) {
+	if b == 0 {
+		return 0, calculator.NewError("divide", calculator.ErrDivisionByZero, a, b)
+	}
+	return a / b, nil
+}
+
+// WithMode divides a by b, overflow decides between Checked, Saturating and Wrapping
+func WithMode[T calculator.Integer](a, b T, overflow calculator.Overflow) (T, error) {
+	switch overflow {
+	case calculator.OverflowSaturate:
+		return Saturating(a, b)
+	case calculator.OverflowWrap:
+		return Wrapping(a, b)
+	}
+	return Checked(a, b)
+}
+
+// QuoRem spells out what integer division does
+// The quotient is truncated towards 0, and the remainder has the sign of a, so that q*b + r == a:
+// QuoRem(7, 2) is 3, 1 and QuoRem(-7, 2) is -3, -1
+func QuoRem[T calculator.Integer](a, b T) (q, r T, err error) {
+	if b == 0 {
+		return 0, 0, calculator.NewError("divide", calculator.ErrDivisionByZero, a, b)
+	}
+	return a / b, a % b, nil
+}
+
+// Remainder returns what is left over after dividing a by b, see QuoRem
+func Remainder[T calculator.Integer](a, b T) (T, error) {
+	_, r, err := QuoRem(a, b)
+	return r, err
+}
+
+// CheckedFloat divides a by b, returning calculator.ErrDivisionByZero when b is 0
+// Floats do not panic on 1 / 0 but give +Inf, which is returned as an error as well
+func CheckedFloat[T calculator.Float](a, b T) (T, error) {
+	if b == 0 {
+		return 0, calculator.NewError("divide", calculator.ErrDivisionByZero, a, b)
+	}
+	return calculator.CheckFloat("divide", a/b, a, b)
+}
+
+// CheckedComplex divides complex numbers like CheckedFloat, returning calculator.ErrDivisionByZero when b is 0
+func CheckedComplex[T calculator.Complex](a, b T) (T, error) {
+	if b == 0 {
+		return 0, calculator.NewError("divide", calculator.ErrDivisionByZero, a, b)
+	}
+	return calculator.CheckComplex("divide", a/b, a, b)
+}
@@ -0,0 +1,31 @@
+package divide
+
+import "github.com/shashank-priyadarshi/training/calculator"
+
+func init() {
+	calculator.Register(calculator.Func{
+		Spec: calculator.Info{
+			Name:        "divide",
+			Symbol:      "/",
+			Arity:       2,
+			Precedence:  calculator.PrecMultiplicative,
+			Description: "a / b divides a by b, integer division drops the fraction",
+		},
+		Int:     calculator.Binary(WithMode[int64]),
+		Uint:    calculator.Binary(WithMode[uint64]),
+		Float:   calculator.FloatBinary(CheckedFloat[float64]),
+		Complex: calculator.ComplexBinary(CheckedComplex[complex128]),
+	})
+
+	calculator.Register(calculator.Func{
+		Spec: calculator.Info{
+			Name:        "remainder",
+			Symbol:      "%",
+			Arity:       2,
+			Precedence:  calculator.PrecMultiplicative,
+			Description: "a % b is what is left over after dividing a by b, it has the sign of a",
+		},
+		Int:  calculator.CheckedBinary(Remainder[int64]),
+		Uint: calculator.CheckedBinary(Remainder[uint64]),
+	})
+}
@@ -0,0 +1,42 @@
+// Package calculator holds what is shared by the calculator packages: add, subtract, multiply and divide
+package calculator
+
+import (
+	"errors"
+	"fmt"
+	"strings"
+)
+
+// Sentinel errors returned by the calculator packages
+// Callers compare against these with errors.Is, instead of matching on the error message
+var (
+	ErrDivisionByZero = errors.New("division by zero") // The divisor was 0
+	ErrOverflow       = errors.New("overflow")         // The result does not fit into the type of the operands
+	ErrInvalidOperand = errors.New("invalid operand")  // An operand is outside of what the operation accepts
+)
+
+// OperationError tells which operation failed, and for which operands
+// It wraps one of the sentinel errors, so errors.Is(err, ErrDivisionByZero) keeps working
+type OperationError struct {
+	Op       string // Name of the operation, e.g "divide"
+	Operands []any  // Operands that were passed to the operation
+	Err      error  // One of the sentinel errors
+}
+
+func (e *OperationError) Error() string {
+	operands := make([]string, 0, len(e.Operands))
+	for _, operand := range e.Operands {
+		operands = append(operands, fmt.Sprint(operand))
+	}
+	return fmt.Sprintf("%s(%s): %v", e.Op, strings.Join(operands, ", "), e.Err)
+}
+
+// Unwrap lets errors.Is and errors.As look at the sentinel error
+func (e *OperationError) Unwrap() error {
+	return e.Err
+}
+
+// NewError returns an *OperationError for the operation op, wrapping err
+func NewError(op string, err error, operands ...any) error {
+	return &OperationError{Op: op, Operands: operands, Err: err}
+}
@@ -0,0 +1,250 @@
+package expr
+
+import (
+	"errors"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+
+	// The operations that come with the calculator register themselves
+	_ "github.com/shashank-priyadarshi/training/calculator/builtin"
+)
+
+// Arithmetic decides which type of number an expression is evaluated with
+// The same expression gives different results with different arithmetics: "1 / 2" is 0 with Int, and 0.5 with Float
+// "127 + 1" fails with Fixed[int8], but is 128 with Int
+type Arithmetic[T any] interface {
+	// Name of the arithmetic, used to select it at runtime, see NewEvaluator
+	Name() string
+	// Literal converts a number written in the expression into a value
+	Literal(text string) (T, error)
+	// Apply applies the operation with the given name from the calculator registry, e.g add, negate or sqrt
+	Apply(name string, args ...T) (T, error)
+	// Format converts a value into text
+	Format(v T) string
+}
+
+// lookup returns the operation name from the calculator registry
+func lookup(name, mode string) (calculator.Operation, error) {
+	op, ok := calculator.Lookup(name)
+	if !ok {
+		return nil, fmt.Errorf("%w %s in %s mode", ErrUnknownFunction, name, mode)
+	}
+	return op, nil
+}
+
+// Fixed evaluates expressions with a fixed-width integer type like int8 or uint16
+// Overflow decides what happens when a result does not fit into T, e.g 127 + 1 with int8
+type Fixed[T calculator.Integer] struct {
+	Overflow calculator.Overflow
+}
+
+// Int evaluates expressions with int, every operation is checked for overflow
+type Int = Fixed[int]
+
+// Name is the name of T, followed by the overflow unless it is calculator.OverflowError, e.g int8:saturate
+func (f Fixed[T]) Name() string {
+	name := fmt.Sprintf("%T", T(0))
+	if f.Overflow != calculator.OverflowError {
+		name += ":" + f.Overflow.String()
+	}
+	return name
+}
+
+func (f Fixed[T]) Literal(text string) (T, error) {
+	if imaginary(text) {
+		return 0, imaginaryError(text, f.Name())
+	}
+	base := literalBase(text)
+	// e is a hexadecimal digit, but p is not, it is the exponent of a hexadecimal float like 0x1p-2
+	if (base == 10 && strings.ContainsAny(text, "eE")) || strings.ContainsAny(text, ".pP") {
+		return 0, fmt.Errorf("%w: %s is not an integer, switch to float mode for fractions", calculator.ErrInvalidOperand, text)
+	}
+
+	var v T
+	var err error
+	if calculator.IsSigned[T]() {
+		var i int64
+		i, err = strconv.ParseInt(text, base, calculator.Bits[T]())
+		v = T(i)
+	} else {
+		// An unsigned type has no negative literals, -5 is 5 negated with the overflow of f, so it agrees with 0 - 5
+		digits, negative := strings.CutPrefix(text, "-")
+		var u uint64
+		u, err = strconv.ParseUint(digits, base, calculator.Bits[T]())
+		v = T(u)
+		if err == nil && negative {
+			return f.Apply("negate", v)
+		}
+	}
+	switch {
+	case errors.Is(err, strconv.ErrRange):
+		return 0, fmt.Errorf("%w: %s does not fit into %T", calculator.ErrOverflow, text, v)
+	case err != nil:
+		return 0, fmt.Errorf("%w: %s is not a number", calculator.ErrInvalidOperand, text)
+	}
+	return v, nil
+}
+
+// Apply computes with int64 or uint64, and converts the result back to T
+// The overflow is applied twice: by the operation for int64 and uint64, and when converting back to a smaller T
+func (f Fixed[T]) Apply(name string, args ...T) (T, error) {
+	op, err := lookup(name, f.Name())
+	if err != nil {
+		return 0, err
+	}
+
+	if calculator.IsSigned[T]() {
+		ints := make([]int64, len(args))
+		for i, arg := range args {
+			ints[i] = int64(arg)
+		}
+		v, err := op.ApplyInt(f.Overflow, ints...)
+		if err != nil {
+			return 0, err
+		}
+		return calculator.NarrowInt[T](name, v, f.Overflow)
+	}
+
+	uints := make([]uint64, len(args))
+	for i, arg := range args {
+		uints[i] = uint64(arg)
+	}
+	v, err := op.ApplyUint(f.Overflow, uints...)
+	if err != nil {
+		return 0, err
+	}
+	if name == "complement" {
+		// ^ flips all 64 bits of the uint64, only the bits of T are flipped in T: ^uint8(5) is 250, not 18446744073709551610
+		return T(v), nil
+	}
+	return calculator.NarrowUint[T](name, v, f.Overflow)
+}
+
+func (f Fixed[T]) Format(v T) string {
+	return f.FormatBase(v, 10)
+}
+
+// FormatBase writes v in base 2 to 36, with the prefix of a Go literal for bases 2, 8 and 16: 255 is 0xff in base 16
+// Negative numbers keep their sign, -5 is -0b101 in base 2, see bitwise.Pattern for the bits in memory
+func (Fixed[T]) FormatBase(v T, base int) string {
+	if calculator.IsSigned[T]() && v < 0 {
+		return "-" + basePrefixes[base] + strconv.FormatUint(-uint64(v), base)
+	}
+	return basePrefixes[base] + strconv.FormatUint(uint64(v), base)
+}
+
+// Float evaluates expressions with float64, NaN and infinite results are returned as errors
+type Float struct{}
+
+func (Float) Name() string {
+	return "float"
+}
+
+func (f Float) Literal(text string) (float64, error) {
+	if imaginary(text) {
+		return 0, imaginaryError(text, f.Name())
+	}
+	v, err := calculator.ParseFloat(text)
+	if err != nil {
+		return 0, literalError(text, "a float64", err)
+	}
+	return v, nil
+}
+
+func (f Float) Apply(name string, args ...float64) (float64, error) {
+	op, err := lookup(name, f.Name())
+	if err != nil {
+		return 0, err
+	}
+	return op.ApplyFloat(args...)
+}
+
+func (Float) Format(v float64) string {
+	return strconv.FormatFloat(v, 'g', -1, 64)
+}
+
+// Complex evaluates expressions with complex128, a number like 3+4i has a real part, 3, and an imaginary part, 4
+// Imaginary literals end with i like in Go, so 3+4i is the sum of the real number 3 and the imaginary number 4i
+type Complex struct{}
+
+func (Complex) Name() string {
+	return "complex"
+}
+
+// Literal reads real numbers like 3 and imaginary numbers like 4i
+func (Complex) Literal(text string) (complex128, error) {
+	if imaginary(text) {
+		v, err := calculator.ParseFloat(strings.TrimSuffix(text, "i"))
+		if err != nil {
+			return 0, literalError(text, "a complex128", err)
+		}
+		return complex(0, v), nil
+	}
+	v, err := calculator.ParseFloat(text)
+	if err != nil {
+		return 0, literalError(text, "a complex128", err)
+	}
+	return complex(v, 0), nil
+}
+
+func (c Complex) Apply(name string, args ...complex128) (complex128, error) {
+	op, err := lookup(name, c.Name())
+	if err != nil {
+		return 0, err
+	}
+	return op.ApplyComplex(args...)
+}
+
+// Format leaves out the parts that are 0, so 3+0i is 3 and 0+4i is 4i, instead of (3+0i) and (0+4i) like fmt does
+func (Complex) Format(v complex128) string {
+	re, im := real(v), imag(v)
+	switch {
+	case im == 0:
+		return strconv.FormatFloat(re, 'g', -1, 64)
+	case re == 0:
+		return strconv.FormatFloat(im, 'g', -1, 64) + "i"
+	}
+	// FormatFloat writes the - of a negative imaginary part, the + of a positive one is added here
+	sign := "+"
+	if im < 0 {
+		sign = ""
+	}
+	return strconv.FormatFloat(re, 'g', -1, 64) + sign + strconv.FormatFloat(im, 'g', -1, 64) + "i"
+}
+
+// imaginary reports whether a number literal is imaginary, like 4i
+func imaginary(text string) bool {
+	return strings.HasSuffix(text, "i")
+}
+
+// imaginaryError explains that 3i is an imaginary literal in every mode, the lexer does not know the mode
+// In int mode, let i = 2 followed by 3i does not multiply 3 by the variable i, 3 * i does
+func imaginaryError(text, mode string) error {
+	return fmt.Errorf("%w: %s is imaginary, switch from %s to complex mode for complex numbers, or write %s * i to multiply by the variable i",
+		calculator.ErrInvalidOperand, text, mode, strings.TrimSuffix(text, "i"))
+}
+
+// basePrefixes are the prefixes of Go integer literals in other bases than 10
+var basePrefixes = map[int]string{2: "0b", 8: "0o", 16: "0x"}
+
+// literalBase returns 0 for integer literals with a base prefix, like 0xff, 0o17 or 0b1010, and 10 for the others
+// strconv reads the prefix when the base is 0, it would also read 017 as octal like Go does,
+// which is surprising in a calculator, so only literals with a letter after the 0 are read with base 0
+func literalBase(text string) int {
+	text = strings.TrimPrefix(text, "-")
+	if len(text) > 2 && text[0] == '0' && strings.ContainsRune("xXoObB", rune(text[1])) {
+		return 0
+	}
+	return 10
+}
+
+// literalError explains why calculator.ParseFloat could not read text into the type, e.g a float64
+func literalError(text, typ string, err error) error {
+	if errors.Is(err, strconv.ErrRange) {
+		return fmt.Errorf("%w: %s does not fit into %s", calculator.ErrOverflow, text, typ)
+	}
+	return fmt.Errorf("%w: %s is not a number", calculator.ErrInvalidOperand, text)
+}
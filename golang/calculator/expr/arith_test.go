@@ -0,0 +1,109 @@
+package expr
+
+import (
+	"errors"
+	"math"
+	"testing"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+)
+
+func TestPrefixedLiteralsInEveryMode(t *testing.T) {
+	for _, tt := range []struct {
+		mode, src, want string
+	}{
+		{"int", "0xff + 0b1010 + 0o17", "280"},
+		{"uint64", "0xffffffffffffffff", "18446744073709551615"},
+		{"float", "0xff", "255"},
+		// Larger than an int64, like the decimal 18446744073709551615 in float mode
+		{"float", "0xffffffffffffffff", "1.8446744073709552e+19"},
+		{"float", "0x1p-2", "0.25"},
+		{"float", "0x1.8p1 * 2", "6"},
+		// Only 0o makes a literal octal, 017 is 17 like on paper
+		{"float", "017", "17"},
+		{"complex", "0xffffffffffffffff", "1.8446744073709552e+19"},
+		{"complex", "0x1p-2 + 2i", "0.25+2i"},
+		{"units", "0xff", "255"},
+		{"units", "0x10 * 2 km", "32 km"},
+	} {
+		session, err := NewEvaluator(tt.mode)
+		if err != nil {
+			t.Fatal(err)
+		}
+		if _, got, err := session.Eval(tt.src); err != nil || got != tt.want {
+			t.Errorf("%s: %s = %s, %v, want %s", tt.mode, tt.src, got, err, tt.want)
+		}
+	}
+}
+
+func TestNegativeUnsignedLiterals(t *testing.T) {
+	// -5 is 5 negated, it agrees with 0 - 5 in every overflow mode
+	for _, tt := range []struct {
+		mode, want string
+		err        error
+	}{
+		{"uint8", "", calculator.ErrOverflow},
+		{"uint8:wrap", "251", nil},
+		{"uint8:saturate", "0", nil},
+		{"uint64:wrap", "18446744073709551611", nil},
+	} {
+		session, err := NewEvaluator(tt.mode)
+		if err != nil {
+			t.Fatal(err)
+		}
+		for _, src := range []string{"-5", "0 - 5", "-0x5"} {
+			if _, got, err := session.Eval(src); got != tt.want || !errors.Is(err, tt.err) {
+				t.Errorf("%s: %s = %q, %v, want %q, %v", tt.mode, src, got, err, tt.want, tt.err)
+			}
+		}
+		if _, got, err := session.Eval("-0"); err != nil || got != "0" {
+			t.Errorf("%s: -0 = %q, %v, want 0", tt.mode, got, err)
+		}
+	}
+}
+
+// literalErr returns the error of a.Literal(text)
+func literalErr[T any](a Arithmetic[T], text string) error {
+	_, err := a.Literal(text)
+	return err
+}
+
+func TestLiteralErrors(t *testing.T) {
+	for _, tt := range []struct {
+		name string
+		got  error
+		want error
+	}{
+		{"1e400 in float", literalErr[float64](Float{}, "1e400"), calculator.ErrOverflow},
+		{"1e400 in complex", literalErr[complex128](Complex{}, "1e400"), calculator.ErrOverflow},
+		{"0xfg in float", literalErr[float64](Float{}, "0xfg"), calculator.ErrInvalidOperand},
+		{"0x1p-2 in int", literalErr[int](Int{}, "0x1p-2"), calculator.ErrInvalidOperand},
+		{"1.5 in int", literalErr[int](Int{}, "1.5"), calculator.ErrInvalidOperand},
+		{"0x100 in uint8", literalErr[uint8](Fixed[uint8]{}, "0x100"), calculator.ErrOverflow},
+	} {
+		if !errors.Is(tt.got, tt.want) {
+			t.Errorf("%s: err = %v, want %v", tt.name, tt.got, tt.want)
+		}
+	}
+}
+
+func TestFormatBase(t *testing.T) {
+	for _, tt := range []struct {
+		got, want string
+	}{
+		{Fixed[uint8]{}.FormatBase(255, 16), "0xff"},
+		{Fixed[uint8]{}.FormatBase(8, 8), "0o10"},
+		{Fixed[int8]{}.FormatBase(-5, 2), "-0b101"},
+		// -128 has no positive int8, the magnitude is computed as a uint64
+		{Fixed[int8]{}.FormatBase(math.MinInt8, 16), "-0x80"},
+		{Int{}.FormatBase(math.MinInt, 10), "-9223372036854775808"},
+		{Fixed[uint64]{}.FormatBase(math.MaxUint64, 16), "0xffffffffffffffff"},
+		// Bases without a Go prefix have none
+		{Int{}.FormatBase(35, 36), "z"},
+		{Int{}.FormatBase(0, 2), "0b0"},
+	} {
+		if tt.got != tt.want {
+			t.Errorf("FormatBase = %s, want %s", tt.got, tt.want)
+		}
+	}
+}
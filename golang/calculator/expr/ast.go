@@ -0,0 +1,64 @@
+package expr
+
+// Node is a part of a parsed expression
+// An expression is parsed into a tree of nodes, e.g "1 + 2 * 3" becomes:
+//
+//	  +
+//	 / \
+//	1   *
+//	   / \
+//	  2   3
+type Node interface {
+	// Pos returns the column of the node in the source, counting from 1
+	Pos() int
+}
+
+// NumberLit is a number written in the expression, e.g 42, or a quantity with a unit, e.g 5 km
+type NumberLit struct {
+	Text string
+	Unit string // Name of the unit written after the number, e.g km, empty when there is none
+	Col  int
+}
+
+// Ident is the name of a variable, e.g x or ans
+type Ident struct {
+	Name string
+	Col  int
+}
+
+// UnaryExpr is an operator applied to a single operand, e.g -x
+type UnaryExpr struct {
+	Op   string // Symbol of the operator, e.g -
+	Name string // Name of the operation in the calculator registry, e.g negate
+	X    Node
+	Col  int
+}
+
+// BinaryExpr is an operator applied to two operands, e.g x + y
+type BinaryExpr struct {
+	Op   string // Symbol of the operator, e.g +
+	Name string // Name of the operation in the calculator registry, e.g add
+	X    Node
+	Y    Node
+	Col  int
+}
+
+// CallExpr is a function called with arguments, e.g pow(2, 10)
+type CallExpr struct {
+	Name string
+	Args []Node
+	Col  int
+}
+
+func (n *NumberLit) Pos() int  { return n.Col }
+func (n *Ident) Pos() int      { return n.Col }
+func (n *UnaryExpr) Pos() int  { return n.Col }
+func (n *BinaryExpr) Pos() int { return n.Col }
+func (n *CallExpr) Pos() int   { return n.Col }
+
+// Statement is one line given to a Session
+// "let x = 3" is a Statement with Name x, while "x + 1" has no Name
+type Statement struct {
+	Name string // Variable to bind the value of Expr to, empty if the value is not bound
+	Expr Node
+}
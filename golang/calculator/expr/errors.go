@@ -0,0 +1,37 @@
+package expr
+
+import (
+	"errors"
+	"fmt"
+)
+
+var (
+	ErrUndefined       = errors.New("undefined variable") // A variable was used before it was bound with let
+	ErrUnknownFunction = errors.New("unknown function")   // A function is not available in the current mode
+	ErrUnknownMode     = errors.New("unknown mode")       // A mode that is not one of Modes was selected
+)
+
+// SyntaxError is returned when an expression cannot be parsed
+type SyntaxError struct {
+	Col int // Column of the offending token, counting from 1
+	Msg string
+}
+
+func (e *SyntaxError) Error() string {
+	return fmt.Sprintf("column %d: %s", e.Col, e.Msg)
+}
+
+// EvalError is returned when a parsed expression cannot be evaluated, e.g when dividing by 0
+// It wraps the error returned by the calculator packages, so errors.Is(err, calculator.ErrDivisionByZero) works
+type EvalError struct {
+	Col int // Column of the operator or number that failed, counting from 1
+	Err error
+}
+
+func (e *EvalError) Error() string {
+	return fmt.Sprintf("column %d: %v", e.Col, e.Err)
+}
+
+func (e *EvalError) Unwrap() error {
+	return e.Err
+}
@@ -0,0 +1,116 @@
+package expr
+
+import (
+	"fmt"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+)
+
+// Env holds the values of variables, by name
+type Env[T any] map[string]T
+
+// Evaluate parses and evaluates src with Int, src cannot use any variables
+func Evaluate(src string) (int, error) {
+	n, err := Parse(src)
+	if err != nil {
+		return 0, err
+	}
+	return Eval[int](n, Int{}, nil)
+}
+
+// UnitArithmetic is an Arithmetic whose numbers can have a unit, e.g 5 km, see package units
+type UnitArithmetic[T any] interface {
+	Arithmetic[T]
+	// UnitLiteral converts a number followed by the name of a unit into a value
+	UnitLiteral(text, unit string) (T, error)
+}
+
+// literal converts a number written in the expression, with its unit if it has one
+// 3i is imaginary in every mode, only the complex mode reads it, the other modes explain why they do not
+func literal[T any](a Arithmetic[T], text, unit string) (T, error) {
+	var zero T
+	if _, ok := any(a).(Complex); !ok && imaginary(text) {
+		return zero, imaginaryError(text, a.Name())
+	}
+	if unit == "" {
+		return a.Literal(text)
+	}
+	if ua, ok := a.(UnitArithmetic[T]); ok {
+		return ua.UnitLiteral(text, unit)
+	}
+	return zero, fmt.Errorf("%w: %s %s has a unit, switch from %s to units mode for quantities with units", calculator.ErrInvalidOperand, text, unit, a.Name())
+}
+
+// Eval evaluates a parsed expression with the arithmetic a, looking up variables in env
+// Errors of the arithmetic, like dividing by 0 or an overflow, are returned as an *EvalError pointing at the operator
+func Eval[T any](n Node, a Arithmetic[T], env Env[T]) (T, error) {
+	var zero T
+
+	switch n := n.(type) {
+	case *NumberLit:
+		v, err := literal(a, n.Text, n.Unit)
+		if err != nil {
+			return zero, &EvalError{Col: n.Col, Err: err}
+		}
+		return v, nil
+
+	case *Ident:
+		v, ok := env[n.Name]
+		if !ok {
+			return zero, &EvalError{Col: n.Col, Err: fmt.Errorf("%w %s", ErrUndefined, n.Name)}
+		}
+		return v, nil
+
+	case *UnaryExpr:
+		// A negative number is read as one literal, because -128 fits into an int8 while 128 does not
+		if lit, ok := n.X.(*NumberLit); ok && n.Name == "negate" {
+			v, err := literal(a, "-"+lit.Text, lit.Unit)
+			if err != nil {
+				return zero, &EvalError{Col: n.Col, Err: err}
+			}
+			return v, nil
+		}
+
+		x, err := Eval(n.X, a, env)
+		if err != nil {
+			return zero, err
+		}
+		v, err := a.Apply(n.Name, x)
+		if err != nil {
+			return zero, &EvalError{Col: n.Col, Err: err}
+		}
+		return v, nil
+
+	case *BinaryExpr:
+		x, err := Eval(n.X, a, env)
+		if err != nil {
+			return zero, err
+		}
+		y, err := Eval(n.Y, a, env)
+		if err != nil {
+			return zero, err
+		}
+		v, err := a.Apply(n.Name, x, y)
+		if err != nil {
+			return zero, &EvalError{Col: n.Col, Err: err}
+		}
+		return v, nil
+
+	case *CallExpr:
+		args := make([]T, len(n.Args))
+		for i, arg := range n.Args {
+			v, err := Eval(arg, a, env)
+			if err != nil {
+				return zero, err
+			}
+			args[i] = v
+		}
+		v, err := a.Apply(n.Name, args...)
+		if err != nil {
+			return zero, &EvalError{Col: n.Col, Err: err}
+		}
+		return v, nil
+	}
+
+	return zero, fmt.Errorf("cannot evaluate %T", n)
+}
@@ -0,0 +1,35 @@
+package expr
+
+import (
+	"errors"
+	"strings"
+	"testing"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+)
+
+func TestImaginaryLiteralOutsideComplexMode(t *testing.T) {
+	for _, mode := range []string{"int", "float", "bigint", "rat", "units"} {
+		session, err := NewEvaluator(mode)
+		if err != nil {
+			t.Fatal(err)
+		}
+		if _, _, err := session.Eval("let i = 2"); err != nil {
+			t.Fatalf("%s: let i = 2: %v", mode, err)
+		}
+
+		// 3i is an imaginary literal and not 3 times i, the error tells how to write the product
+		_, _, err = session.Eval("3i")
+		if !errors.Is(err, calculator.ErrInvalidOperand) || !strings.Contains(err.Error(), "3 * i") {
+			t.Errorf("%s: 3i err = %v, want ErrInvalidOperand mentioning 3 * i", mode, err)
+		}
+		if _, v, err := session.Eval("3 * i"); err != nil || v != "6" {
+			t.Errorf("%s: 3 * i = %s, %v, want 6", mode, v, err)
+		}
+	}
+
+	session, _ := NewEvaluator("complex")
+	if _, _, err := session.Eval("3i"); err != nil {
+		t.Errorf("complex: 3i err = %v, want an imaginary number", err)
+	}
+}
@@ -0,0 +1,163 @@
+// Package expr parses infix arithmetic expressions like "(3 + 4) * 2 / 7" and evaluates them
+// using the add, subtract, multiply and divide packages
+package expr
+
+import (
+	"strings"
+	"unicode"
+	"unicode/utf8"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+)
+
+// Kind tells what a token is
+type Kind int
+
+const (
+	EOF        Kind = iota // End of the input
+	Number                 // 42, 1.5, 2e3, 0xff, and imaginary numbers like 4i
+	Identifier             // Name of a variable or a function, e.g x or sqrt
+	Operator               // A registered operator symbol, e.g + or -
+	Assign                 // = in "let x = 3"
+	LParen                 // (
+	RParen                 // )
+	Comma                  // , between the arguments of a function
+)
+
+// Token is the smallest meaningful piece of an expression
+// Col is the column where the token starts, counting from 1, and is used to point at errors
+type Token struct {
+	Kind Kind
+	Text string
+	Col  int
+}
+
+// Tokenize splits src into tokens, the last token is always EOF
+func Tokenize(src string) ([]Token, error) {
+	runes := []rune(src)
+	symbols := calculator.Symbols() // Once per expression, not once per operator
+	var tokens []Token
+
+	for i := 0; i < len(runes); {
+		r := runes[i]
+		col := i + 1
+
+		switch {
+		case unicode.IsSpace(r):
+			i++
+		case hasBasePrefix(runes, i):
+			// 0xff, 0o17 and 0b1010 are integers in base 16, 8 and 2, the letters are checked by the arithmetic
+			start := i
+			i = name(runes, i+2)
+			// A hexadecimal float has a fraction and a binary exponent with a sign: 0x1.8p1, 0x1p-2
+			if i+1 < len(runes) && runes[i] == '.' && unicode.Is(unicode.ASCII_Hex_Digit, runes[i+1]) {
+				i = name(runes, i+1)
+			}
+			if i+1 < len(runes) && (runes[i-1] == 'p' || runes[i-1] == 'P') && (runes[i] == '+' || runes[i] == '-') &&
+				unicode.IsDigit(runes[i+1]) {
+				i = digits(runes, i+1)
+			}
+			tokens = append(tokens, Token{Kind: Number, Text: string(runes[start:i]), Col: col})
+		case unicode.IsDigit(r):
+			start := i
+			i = digits(runes, i)
+			// A fraction and an exponent make it a floating-point number: 1.5, 2e3, 1.5e-3
+			if i+1 < len(runes) && runes[i] == '.' && unicode.IsDigit(runes[i+1]) {
+				i = digits(runes, i+1)
+			}
+			if i < len(runes) && (runes[i] == 'e' || runes[i] == 'E') {
+				j := i + 1
+				if j < len(runes) && (runes[j] == '+' || runes[j] == '-') {
+					j++
+				}
+				if j < len(runes) && unicode.IsDigit(runes[j]) {
+					i = digits(runes, j)
+				}
+			}
+			// An i right after a number makes it imaginary like in Go: 4i, 1.5i, unless it starts a name like in 4if
+			if i < len(runes) && runes[i] == 'i' && (i+1 == len(runes) || !isNameRune(runes[i+1])) {
+				i++
+			}
+			tokens = append(tokens, Token{Kind: Number, Text: string(runes[start:i]), Col: col})
+		case unicode.IsLetter(r) || r == '_':
+			start := i
+			i = name(runes, i)
+			tokens = append(tokens, Token{Kind: Identifier, Text: string(runes[start:i]), Col: col})
+		case r == '=':
+			tokens = append(tokens, Token{Kind: Assign, Text: "=", Col: col})
+			i++
+		case r == ',':
+			tokens = append(tokens, Token{Kind: Comma, Text: ",", Col: col})
+			i++
+		case r == '(':
+			tokens = append(tokens, Token{Kind: LParen, Text: "(", Col: col})
+			i++
+		case r == ')':
+			tokens = append(tokens, Token{Kind: RParen, Text: ")", Col: col})
+			i++
+		default:
+			symbol := operator(runes[i:], symbols)
+			if symbol == "" {
+				return nil, &SyntaxError{Col: col, Msg: "unexpected character " + string(r)}
+			}
+			tokens = append(tokens, Token{Kind: Operator, Text: symbol, Col: col})
+			i += utf8.RuneCountInString(symbol)
+		}
+	}
+
+	return append(tokens, Token{Kind: EOF, Col: len(runes) + 1}), nil
+}
+
+// digits returns the index of the first rune at or after i that is not a digit
+func digits(runes []rune, i int) int {
+	for i < len(runes) && unicode.IsDigit(runes[i]) {
+		i++
+	}
+	return i
+}
+
+// name returns the index of the first rune at or after i that cannot be part of a name, see isNameRune
+func name(runes []rune, i int) int {
+	for i < len(runes) && isNameRune(runes[i]) {
+		i++
+	}
+	return i
+}
+
+// hasBasePrefix reports whether runes[i:] starts with 0x, 0o or 0b followed by a digit,
+// 0B alone stays the number 0 followed by the unit B
+func hasBasePrefix(runes []rune, i int) bool {
+	if i+2 >= len(runes) || runes[i] != '0' || !strings.ContainsRune("xXoObB", runes[i+1]) {
+		return false
+	}
+	return unicode.Is(unicode.ASCII_Hex_Digit, runes[i+2])
+}
+
+// operator returns the longest of the symbols at the start of runes, or "" if there is none
+// The symbols are sorted longest first by calculator.Symbols, so the first match is the longest
+func operator(runes []rune, symbols []string) string {
+	for _, symbol := range symbols {
+		if hasPrefix(runes, symbol) {
+			return symbol
+		}
+	}
+	return ""
+}
+
+// hasPrefix reports whether runes starts with prefix, without turning the rest of the input into a string,
+// which would make the lexer copy the input once per operator
+func hasPrefix(runes []rune, prefix string) bool {
+	i := 0
+	for _, r := range prefix {
+		if i == len(runes) || runes[i] != r {
+			return false
+		}
+		i++
+	}
+	return true
+}
+
+// isNameRune reports whether r can be part of the name of a variable or a function
+func isNameRune(r rune) bool {
+	return unicode.IsLetter(r) || unicode.IsDigit(r) || r == '_'
+}
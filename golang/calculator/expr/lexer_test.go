@@ -0,0 +1,65 @@
+package expr
+
+import (
+	"errors"
+	"slices"
+	"testing"
+)
+
+func TestTokenize(t *testing.T) {
+	for _, tt := range []struct {
+		src  string
+		want []string
+	}{
+		{"1 + 2", []string{"1", "+", "2"}},
+		{"(3+4)*2", []string{"(", "3", "+", "4", ")", "*", "2"}},
+		{"1.5e-3 * 2E3", []string{"1.5e-3", "*", "2E3"}},
+		{"0xff&0b1010|0o17", []string{"0xff", "&", "0b1010", "|", "0o17"}},
+		{"0x1p-2 + 0x1.8p1", []string{"0x1p-2", "+", "0x1.8p1"}},
+		// e is a hexadecimal digit, not an exponent
+		{"0xe-2", []string{"0xe", "-", "2"}},
+		// 0B without a digit after it is the number 0 followed by the unit B
+		{"0B", []string{"0", "B"}},
+		{"4i + 1.5i", []string{"4i", "+", "1.5i"}},
+		{"4if", []string{"4", "if"}},
+		// The longest symbol wins: &^ is one operator, not & followed by ^
+		{"12 &^ 10 << 1", []string{"12", "&^", "10", "<<", "1"}},
+		{"let x = max(1, 2)", []string{"let", "x", "=", "max", "(", "1", ",", "2", ")"}},
+	} {
+		tokens, err := Tokenize(tt.src)
+		if err != nil {
+			t.Errorf("Tokenize(%q): %v", tt.src, err)
+			continue
+		}
+		if last := tokens[len(tokens)-1]; last.Kind != EOF {
+			t.Errorf("Tokenize(%q) ends with %+v, want EOF", tt.src, last)
+			continue
+		}
+		var got []string
+		for _, token := range tokens[:len(tokens)-1] {
+			got = append(got, token.Text)
+		}
+		if !slices.Equal(got, tt.want) {
+			t.Errorf("Tokenize(%q) = %q, want %q", tt.src, got, tt.want)
+		}
+	}
+}
+
+func TestTokenizeColumns(t *testing.T) {
+	// Columns count runes, not bytes, so the caret of the REPL points at the right character after a π
+	tokens, err := Tokenize("π +  12")
+	if err != nil {
+		t.Fatal(err)
+	}
+	for i, want := range []int{1, 3, 6, 8} {
+		if tokens[i].Col != want {
+			t.Errorf("token %d %q: column %d, want %d", i, tokens[i].Text, tokens[i].Col, want)
+		}
+	}
+
+	_, err = Tokenize("1 $ 2")
+	var syntax *SyntaxError
+	if !errors.As(err, &syntax) || syntax.Col != 3 {
+		t.Errorf("Tokenize(1 $ 2) err = %v, want a SyntaxError at column 3", err)
+	}
+}
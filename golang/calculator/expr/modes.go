@@ -0,0 +1,80 @@
+package expr
+
+import (
+	"fmt"
+	"math/big"
+	"sort"
+	"strings"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+	"github.com/shashank-priyadarshi/training/calculator/bignum"
+	"github.com/shashank-priyadarshi/training/calculator/rational"
+	"github.com/shashank-priyadarshi/training/calculator/units"
+)
+
+// modes are the arithmetics that can be selected at runtime by name
+var modes = map[string]func() Evaluator{
+	Float{}.Name():          func() Evaluator { return NewSession[float64](Float{}) },
+	Complex{}.Name():        func() Evaluator { return NewSession[complex128](Complex{}) },
+	bignum.Int{}.Name():     func() Evaluator { return NewSession[*big.Int](bignum.Int{}) },
+	bignum.Rat{}.Name():     func() Evaluator { return NewSession[*big.Rat](bignum.Rat{}) },
+	bignum.Float{}.Name():   func() Evaluator { return NewSession[*big.Float](bignum.Float{}) },
+	rational.Arith{}.Name(): func() Evaluator { return NewSession[rational.Rational](rational.Arith{}) },
+	units.Arith{}.Name():    func() Evaluator { return NewSession[units.Quantity](units.Arith{}) },
+}
+
+// fixedModes are the integer arithmetics, their name can be followed by an overflow, e.g int8:saturate or uint8:wrap
+var fixedModes = map[string]func(calculator.Overflow) Evaluator{
+	"int":    fixed[int],
+	"int8":   fixed[int8],
+	"int16":  fixed[int16],
+	"int32":  fixed[int32],
+	"int64":  fixed[int64],
+	"uint":   fixed[uint],
+	"uint8":  fixed[uint8],
+	"uint16": fixed[uint16],
+	"uint32": fixed[uint32],
+	"uint64": fixed[uint64],
+}
+
+func fixed[T calculator.Integer](overflow calculator.Overflow) Evaluator {
+	return NewSession[T](Fixed[T]{Overflow: overflow})
+}
+
+// DefaultMode is the mode used when none is selected
+const DefaultMode = "int"
+
+// NewEvaluator returns an Evaluator for the mode with the given name, see Modes
+func NewEvaluator(mode string) (Evaluator, error) {
+	name, overflow, hasOverflow := strings.Cut(mode, ":")
+	if newFixed, ok := fixedModes[name]; ok {
+		o := calculator.OverflowError
+		if hasOverflow {
+			var err error
+			if o, err = calculator.ParseOverflow(overflow); err != nil {
+				return nil, fmt.Errorf("%w %q: %w", ErrUnknownMode, mode, err)
+			}
+		}
+		return newFixed(o), nil
+	}
+
+	newEvaluator, ok := modes[mode]
+	if !ok {
+		return nil, fmt.Errorf("%w %q, available modes are %v", ErrUnknownMode, mode, Modes())
+	}
+	return newEvaluator(), nil
+}
+
+// Modes returns the names of all modes, sorted by name
+// Integer modes can be followed by an overflow, see calculator.ParseOverflow
+func Modes() []string {
+	names := make([]string, 0, len(modes)+len(fixedModes))
+	for name := range modes {
+		names = append(names, name)
+	}
+	for name := range fixedModes {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return names
+}
@@ -0,0 +1,194 @@
+package expr
+
+import (
+	"fmt"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+)
+
+// Parse parses src into a tree of nodes
+// Operators and their precedence come from the calculator registry: "1 + 2 * 3" is 1 + (2 * 3),
+// because * has a higher precedence than +
+// All binary operators are left associative: "8 / 4 / 2" is (8 / 4) / 2
+// An operator in front of an operand is a unary operator: "-3 * 2" is (-3) * 2
+func Parse(src string) (Node, error) {
+	tokens, err := Tokenize(src)
+	if err != nil {
+		return nil, err
+	}
+
+	return (&parser{tokens: tokens}).all()
+}
+
+// ParseStatement parses either an expression, or a binding of the form "let name = expression"
+func ParseStatement(src string) (Statement, error) {
+	tokens, err := Tokenize(src)
+	if err != nil {
+		return Statement{}, err
+	}
+
+	p := &parser{tokens: tokens}
+	if t := p.peek(); t.Kind != Identifier || t.Text != "let" {
+		n, err := p.all()
+		return Statement{Expr: n}, err
+	}
+	p.next()
+
+	name := p.next()
+	if name.Kind != Identifier {
+		return Statement{}, &SyntaxError{Col: name.Col, Msg: "expected a variable name after let"}
+	}
+	if reserved[name.Text] {
+		return Statement{}, &SyntaxError{Col: name.Col, Msg: fmt.Sprintf("%s cannot be used as a variable name", name.Text)}
+	}
+	if t := p.next(); t.Kind != Assign {
+		return Statement{}, &SyntaxError{Col: t.Col, Msg: fmt.Sprintf("expected = after let %s", name.Text)}
+	}
+
+	n, err := p.all()
+	return Statement{Name: name.Text, Expr: n}, err
+}
+
+// reserved names cannot be bound with let
+var reserved = map[string]bool{
+	"let": true,
+	"ans": true, // ans always holds the previous result
+}
+
+type parser struct {
+	tokens []Token
+	pos    int
+}
+
+// all parses an expression that must use up all of the tokens
+func (p *parser) all() (Node, error) {
+	n, err := p.expression(1)
+	if err != nil {
+		return nil, err
+	}
+	if t := p.peek(); t.Kind != EOF {
+		return nil, p.unexpected(t)
+	}
+	return n, nil
+}
+
+func (p *parser) peek() Token {
+	return p.tokens[p.pos]
+}
+
+func (p *parser) next() Token {
+	t := p.tokens[p.pos]
+	if t.Kind != EOF {
+		p.pos++
+	}
+	return t
+}
+
+// expression parses operands joined by binary operators whose precedence is at least minPrec
+// This technique is called precedence climbing
+func (p *parser) expression(minPrec int) (Node, error) {
+	left, err := p.unary()
+	if err != nil {
+		return nil, err
+	}
+
+	for {
+		t := p.peek()
+		if t.Kind != Operator {
+			return left, nil
+		}
+		op, ok := calculator.LookupSymbol(t.Text, 2)
+		if !ok || op.Info().Precedence < minPrec {
+			return left, nil
+		}
+		p.next()
+
+		// prec + 1 makes the operator left associative: the right operand stops at the next operator of the same precedence
+		right, err := p.expression(op.Info().Precedence + 1)
+		if err != nil {
+			return nil, err
+		}
+		left = &BinaryExpr{Op: t.Text, Name: op.Info().Name, X: left, Y: right, Col: t.Col}
+	}
+}
+
+func (p *parser) unary() (Node, error) {
+	t := p.peek()
+	if t.Kind != Operator {
+		return p.primary()
+	}
+	op, ok := calculator.LookupSymbol(t.Text, 1)
+	if !ok {
+		return p.primary()
+	}
+	p.next()
+
+	x, err := p.unary()
+	if err != nil {
+		return nil, err
+	}
+	return &UnaryExpr{Op: t.Text, Name: op.Info().Name, X: x, Col: t.Col}, nil
+}
+
+func (p *parser) primary() (Node, error) {
+	t := p.next()
+	switch t.Kind {
+	case Number:
+		lit := &NumberLit{Text: t.Text, Col: t.Col}
+		// A name right after a number is its unit, e.g 5 km, unless it is a function call
+		if unit := p.peek(); unit.Kind == Identifier && p.tokens[p.pos+1].Kind != LParen {
+			lit.Unit = p.next().Text
+		}
+		return lit, nil
+	case Identifier:
+		if p.peek().Kind == LParen {
+			return p.call(t)
+		}
+		return &Ident{Name: t.Text, Col: t.Col}, nil
+	case LParen:
+		n, err := p.expression(1)
+		if err != nil {
+			return nil, err
+		}
+		if closing := p.next(); closing.Kind != RParen {
+			return nil, &SyntaxError{Col: closing.Col, Msg: fmt.Sprintf("missing ) for ( at column %d", t.Col)}
+		}
+		return n, nil
+	default:
+		return nil, p.unexpected(t)
+	}
+}
+
+// call parses the arguments of the function name, separated by commas
+func (p *parser) call(name Token) (Node, error) {
+	open := p.next()
+	call := &CallExpr{Name: name.Text, Col: name.Col}
+	if p.peek().Kind == RParen {
+		p.next()
+		return call, nil
+	}
+
+	for {
+		arg, err := p.expression(1)
+		if err != nil {
+			return nil, err
+		}
+		call.Args = append(call.Args, arg)
+
+		switch t := p.next(); t.Kind {
+		case Comma:
+			continue
+		case RParen:
+			return call, nil
+		default:
+			return nil, &SyntaxError{Col: t.Col, Msg: fmt.Sprintf("expected , or ) for ( at column %d", open.Col)}
+		}
+	}
+}
+
+func (p *parser) unexpected(t Token) error {
+	if t.Kind == EOF {
+		return &SyntaxError{Col: t.Col, Msg: "unexpected end of expression"}
+	}
+	return &SyntaxError{Col: t.Col, Msg: fmt.Sprintf("unexpected %q", t.Text)}
+}
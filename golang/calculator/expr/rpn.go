@@ -0,0 +1,131 @@
+package expr
+
+import (
+	"errors"
+	"fmt"
+	"unicode"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+)
+
+// ErrStackUnderflow is returned when an RPN operation needs more values than there are on the stack
+var ErrStackUnderflow = errors.New("not enough values on the stack")
+
+// RPN evaluates src in Reverse Polish Notation, where operators come after their operands: "3 4 + 2 *" is (3 + 4) * 2
+// Numbers and variables are pushed on the stack, and an operator pops its operands and pushes its result
+// The stack is a LIFO (Last In First Out) data structure, like a stack of plates: the last value pushed is the first one popped
+//
+// Besides operators and functions like sqrt, these commands change the stack:
+//
+//	dup    pushes the top value again
+//	swap   swaps the two top values
+//	drop   pops the top value
+//	clear  removes every value
+//
+// - always subtracts, negate changes the sign of the top value, and ^ is always xor, complement flips the bits
+// The stack is kept between calls, and the top value is stored in ans
+// When a token fails, the stack is left as it was before the call
+func (s *Session[T]) RPN(src string) ([]string, error) {
+	stack := append([]T(nil), s.stack...)
+
+	for _, t := range fields(src) {
+		var err error
+		if stack, err = s.rpnStep(stack, t.Text); err != nil {
+			return s.Stack(), &EvalError{Col: t.Col, Err: err}
+		}
+	}
+
+	s.stack = stack
+	if len(stack) > 0 {
+		s.env["ans"] = stack[len(stack)-1]
+	}
+	return s.Stack(), nil
+}
+
+// Stack returns the formatted values on the RPN stack, the top of the stack is the last value
+func (s *Session[T]) Stack() []string {
+	values := make([]string, len(s.stack))
+	for i, v := range s.stack {
+		values[i] = s.format(v)
+	}
+	return values
+}
+
+func (s *Session[T]) rpnStep(stack []T, token string) ([]T, error) {
+	switch token {
+	case "dup":
+		if len(stack) < 1 {
+			return nil, fmt.Errorf("%w: dup needs 1", ErrStackUnderflow)
+		}
+		return append(stack, stack[len(stack)-1]), nil
+	case "swap":
+		if len(stack) < 2 {
+			return nil, fmt.Errorf("%w: swap needs 2", ErrStackUnderflow)
+		}
+		n := len(stack)
+		stack[n-1], stack[n-2] = stack[n-2], stack[n-1]
+		return stack, nil
+	case "drop":
+		if len(stack) < 1 {
+			return nil, fmt.Errorf("%w: drop needs 1", ErrStackUnderflow)
+		}
+		return stack[:len(stack)-1], nil
+	case "clear":
+		return stack[:0], nil
+	}
+
+	if op, ok := rpnOperation(token); ok {
+		info := op.Info()
+		if len(stack) < info.Arity {
+			return nil, fmt.Errorf("%w: %s needs %d", ErrStackUnderflow, token, info.Arity)
+		}
+		v, err := s.arith.Apply(info.Name, stack[len(stack)-info.Arity:]...)
+		if err != nil {
+			return nil, err
+		}
+		return append(stack[:len(stack)-info.Arity], v), nil
+	}
+
+	if v, ok := s.env[token]; ok {
+		return append(stack, v), nil
+	}
+	if r := []rune(token)[0]; unicode.IsLetter(r) || r == '_' {
+		return nil, fmt.Errorf("%w %s", ErrUndefined, token)
+	}
+
+	v, err := s.arith.Literal(token)
+	if err != nil {
+		return nil, err
+	}
+	return append(stack, v), nil
+}
+
+// rpnOperation returns the operation for a token: a binary operator, a unary operator, or a function like sqrt
+// - is always the binary operator in RPN, the negate function changes the sign instead
+func rpnOperation(token string) (calculator.Operation, bool) {
+	if op, ok := calculator.LookupSymbol(token, 2); ok {
+		return op, true
+	}
+	if op, ok := calculator.LookupSymbol(token, 1); ok {
+		return op, true
+	}
+	return calculator.Lookup(token)
+}
+
+// fields splits src around spaces like strings.Fields, keeping the column of every field
+func fields(src string) []Token {
+	var tokens []Token
+	runes := []rune(src)
+	for i := 0; i < len(runes); {
+		if unicode.IsSpace(runes[i]) {
+			i++
+			continue
+		}
+		start := i
+		for i < len(runes) && !unicode.IsSpace(runes[i]) {
+			i++
+		}
+		tokens = append(tokens, Token{Text: string(runes[start:i]), Col: start + 1})
+	}
+	return tokens
+}
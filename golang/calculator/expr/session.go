@@ -0,0 +1,125 @@
+package expr
+
+import (
+	"fmt"
+	"sort"
+)
+
+// Evaluator evaluates one statement after another, see Session
+// Values are returned as text, so that sessions with different arithmetics can be swapped at runtime
+type Evaluator interface {
+	// Mode is the name of the arithmetic
+	Mode() string
+	// Eval evaluates one statement and returns the formatted result
+	Eval(src string) (Statement, string, error)
+	// Vars returns the names of all variables, sorted by name
+	Vars() []string
+	// Lookup returns the formatted value of a variable
+	Lookup(name string) (string, bool)
+	// RPN evaluates a line in Reverse Polish Notation and returns the stack, see Session.RPN
+	RPN(src string) ([]string, error)
+	// Stack returns the RPN stack, the top of the stack is the last value
+	Stack() []string
+	// SetBase changes the base results are written in, see Session.SetBase
+	SetBase(base int) error
+}
+
+// BaseArithmetic is an Arithmetic that can write its values in other bases than 10, like the integer arithmetics
+type BaseArithmetic[T any] interface {
+	Arithmetic[T]
+	// FormatBase converts a value into text in a base from 2 to 36
+	FormatBase(v T, base int) string
+}
+
+// Session evaluates one statement after another with the same arithmetic, remembering variables bound with let
+// The result of the last successful statement is available as ans
+type Session[T any] struct {
+	arith Arithmetic[T]
+	env   Env[T]
+	stack []T // Stack of the RPN mode
+	base  int // Base results are written in, 10 unless changed with SetBase
+}
+
+// NewSession returns a Session without any variables
+func NewSession[T any](a Arithmetic[T]) *Session[T] {
+	return &Session[T]{arith: a, env: Env[T]{}, base: 10}
+}
+
+func (s *Session[T]) Mode() string {
+	return s.arith.Name()
+}
+
+// Eval evaluates one statement, see ParseStatement
+// On success the result is stored in ans, and also in the variable when the statement is a let binding
+func (s *Session[T]) Eval(src string) (Statement, string, error) {
+	stmt, v, err := s.EvalValue(src)
+	if err != nil {
+		return stmt, "", err
+	}
+	return stmt, s.format(v), nil
+}
+
+// EvalValue works like Eval, but returns the value instead of its text
+func (s *Session[T]) EvalValue(src string) (Statement, T, error) {
+	var zero T
+
+	stmt, err := ParseStatement(src)
+	if err != nil {
+		return stmt, zero, err
+	}
+
+	v, err := Eval(stmt.Expr, s.arith, s.env)
+	if err != nil {
+		return stmt, zero, err
+	}
+
+	if stmt.Name != "" {
+		s.env[stmt.Name] = v
+	}
+	s.env["ans"] = v
+	return stmt, v, nil
+}
+
+func (s *Session[T]) Vars() []string {
+	names := make([]string, 0, len(s.env))
+	for name := range s.env {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return names
+}
+
+func (s *Session[T]) Lookup(name string) (string, bool) {
+	v, ok := s.Get(name)
+	if !ok {
+		return "", false
+	}
+	return s.format(v), true
+}
+
+// Get returns the value of a variable
+func (s *Session[T]) Get(name string) (T, bool) {
+	v, ok := s.env[name]
+	return v, ok
+}
+
+// SetBase makes the session write results in a base from 2 to 36, e.g 16 writes 255 as 0xff
+// Only arithmetics that implement BaseArithmetic can use another base than 10, floats are always written in base 10
+func (s *Session[T]) SetBase(base int) error {
+	if base < 2 || base > 36 {
+		return fmt.Errorf("base %d is not between 2 and 36", base)
+	}
+	if _, ok := s.arith.(BaseArithmetic[T]); !ok && base != 10 {
+		return fmt.Errorf("%s mode only writes numbers in base 10", s.arith.Name())
+	}
+	s.base = base
+	return nil
+}
+
+// format converts a value into text in the base of the session
+func (s *Session[T]) format(v T) string {
+	if ba, ok := s.arith.(BaseArithmetic[T]); ok && s.base != 10 {
+		return ba.FormatBase(v, s.base)
+	}
+	return s.arith.Format(v)
+}
@@ -0,0 +1,57 @@
+package matrix
+
+import "github.com/shashank-priyadarshi/training/calculator"
+
+// Mat2 is a 2x2 matrix, an array of 2 rows of 2 columns
+// Unlike Matrix, its size is part of its type: a Mat2 cannot have 3 rows, and no shape has to be checked at runtime
+// Arrays are values, so assigning a Mat2 or passing it to a function copies all 4 elements
+type Mat2 [2][2]float64
+
+// Matrix converts m into a Matrix, e.g to multiply it by a matrix of another size
+// m is a copy of the caller's Mat2, so the slices of its rows do not share memory with the caller
+func (m Mat2) Matrix() Matrix {
+	return Matrix{rows: [][]float64{m[0][:], m[1][:]}}
+}
+
+// Add returns m + o
+func (m Mat2) Add(o Mat2) Mat2 {
+	var r Mat2 // The zero value of an array is filled with zeros, like a Matrix made by Zero
+	for i := range m {
+		for j := range m[i] {
+			r[i][j] = m[i][j] + o[i][j]
+		}
+	}
+	return r
+}
+
+// Multiply returns the matrix product m * o
+func (m Mat2) Multiply(o Mat2) Mat2 {
+	return Mat2{
+		{m[0][0]*o[0][0] + m[0][1]*o[1][0], m[0][0]*o[0][1] + m[0][1]*o[1][1]},
+		{m[1][0]*o[0][0] + m[1][1]*o[1][0], m[1][0]*o[0][1] + m[1][1]*o[1][1]},
+	}
+}
+
+// Transpose returns m flipped over its diagonal
+func (m Mat2) Transpose() Mat2 {
+	return Mat2{{m[0][0], m[1][0]}, {m[0][1], m[1][1]}}
+}
+
+// Determinant returns ad - bc for the matrix [[a b] [c d]]
+func (m Mat2) Determinant() float64 {
+	return m[0][0]*m[1][1] - m[0][1]*m[1][0]
+}
+
+// Inverse returns [[d -b] [-c a]] / (ad - bc), it fails with ErrSingular when ad - bc is 0
+// ad - bc is a product of two elements, so it is compared with the square of the largest element, see Epsilon
+func (m Mat2) Inverse() (Mat2, error) {
+	det := m.Determinant()
+	scale := largest([][]float64{m[0][:], m[1][:]})
+	if negligible(det, scale*scale) {
+		return Mat2{}, calculator.NewError("inverse", ErrSingular, m)
+	}
+	return Mat2{
+		{m[1][1] / det, -m[0][1] / det},
+		{-m[1][0] / det, m[0][0] / det},
+	}, nil
+}
@@ -0,0 +1,296 @@
+// Package matrix implements matrices of float64s: add, subtract, multiply, transpose, determinant and inverse
+//
+// Matrix has any number of rows and columns, chosen at runtime, so it is a slice of slices: [][]float64
+// Mat2 is always 2x2, so it is an array of arrays: [2][2]float64, the size is part of the type and checked at compile time
+// See types.arrays and types.slices for the difference between arrays and slices
+package matrix
+
+import (
+	"errors"
+	"fmt"
+	"math"
+	"strings"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+	"github.com/shashank-priyadarshi/training/calculator/add"
+	"github.com/shashank-priyadarshi/training/calculator/multiply"
+	"github.com/shashank-priyadarshi/training/calculator/subtract"
+)
+
+var (
+	// ErrSingular is returned when a matrix has no inverse, like 0 has no inverse in divide.Checked
+	// A matrix is singular when its determinant is 0, e.g when a row is a multiple of another row
+	ErrSingular = errors.New("singular matrix")
+	// ErrShape is returned when the number of rows or columns does not fit the operation, e.g when adding a 2x2 to a 3x3
+	ErrShape = errors.New("shapes do not match")
+)
+
+// Epsilon is how close to 0 a pivot can get before the matrix is treated as singular, relative to the largest element
+// Floats are not exact, so a matrix that is singular on paper can have a determinant of 1e-17 instead of 0
+// The test is relative because the scale of the elements does not change whether a matrix is singular:
+// [[1e-7 0] [0 1e-7]] has the determinant 1e-14 and is as easy to invert as the identity
+const Epsilon = 1e-12
+
+// Matrix is a dense matrix, every element is stored, row after row
+// The zero value is a matrix with 0 rows and 0 columns
+type Matrix struct {
+	rows [][]float64
+}
+
+// New returns a matrix with a copy of rows, every row must have the same number of columns
+func New(rows [][]float64) (Matrix, error) {
+	m := Zero(len(rows), 0)
+	for i, row := range rows {
+		if len(row) != len(rows[0]) {
+			return Matrix{}, fmt.Errorf("%w: row %d has %d columns, row 0 has %d", ErrShape, i, len(row), len(rows[0]))
+		}
+		m.rows[i] = append([]float64(nil), row...)
+	}
+	return m, nil
+}
+
+// Zero returns a matrix with r rows and c columns filled with 0
+func Zero(r, c int) Matrix {
+	rows := make([][]float64, r)
+	for i := range rows {
+		rows[i] = make([]float64, c)
+	}
+	return Matrix{rows: rows}
+}
+
+// Identity returns the n x n matrix with 1 on the diagonal and 0 everywhere else, multiplying by it changes nothing
+func Identity(n int) Matrix {
+	m := Zero(n, n)
+	for i := 0; i < n; i++ {
+		m.rows[i][i] = 1
+	}
+	return m
+}
+
+// Rows returns the number of rows
+func (m Matrix) Rows() int {
+	return len(m.rows)
+}
+
+// Cols returns the number of columns
+func (m Matrix) Cols() int {
+	if len(m.rows) == 0 {
+		return 0
+	}
+	return len(m.rows[0])
+}
+
+// At returns the element at row i and column j, counting from 0
+func (m Matrix) At(i, j int) float64 {
+	return m.rows[i][j]
+}
+
+// Slice returns a copy of the elements, changing it does not change m
+func (m Matrix) Slice() [][]float64 {
+	rows := make([][]float64, len(m.rows))
+	for i, row := range m.rows {
+		rows[i] = append([]float64(nil), row...)
+	}
+	return rows
+}
+
+// String writes the rows like fmt writes a [][]float64, e.g [[1 2] [3 4]]
+func (m Matrix) String() string {
+	return fmt.Sprint(m.rows)
+}
+
+// Grid writes one row per line, which is easier to read for bigger matrices
+func (m Matrix) Grid() string {
+	lines := make([]string, len(m.rows))
+	for i, row := range m.rows {
+		lines[i] = fmt.Sprint(row)
+	}
+	return strings.Join(lines, "\n")
+}
+
+// Add returns m + o, element by element, m and o must have the same shape
+func (m Matrix) Add(o Matrix) (Matrix, error) {
+	return m.elementwise("add", o, add.CheckedFloat[float64])
+}
+
+// Subtract returns m - o, element by element, m and o must have the same shape
+func (m Matrix) Subtract(o Matrix) (Matrix, error) {
+	return m.elementwise("subtract", o, subtract.CheckedFloat[float64])
+}
+
+// Multiply returns the matrix product m * o, the element at i, j is the sum of row i of m times column j of o
+// m must have as many columns as o has rows, the result has the rows of m and the columns of o
+func (m Matrix) Multiply(o Matrix) (Matrix, error) {
+	if m.Cols() != o.Rows() {
+		return Matrix{}, calculator.NewError("multiply", fmt.Errorf("%w: %dx%d * %dx%d", ErrShape, m.Rows(), m.Cols(), o.Rows(), o.Cols()), m, o)
+	}
+
+	result := Zero(m.Rows(), o.Cols())
+	for i := range result.rows {
+		for j := range result.rows[i] {
+			sum := 0.0
+			for k := 0; k < m.Cols(); k++ {
+				product, err := multiply.CheckedFloat(m.rows[i][k], o.rows[k][j])
+				if err != nil {
+					return Matrix{}, err
+				}
+				if sum, err = add.CheckedFloat(sum, product); err != nil {
+					return Matrix{}, err
+				}
+			}
+			result.rows[i][j] = sum
+		}
+	}
+	return result, nil
+}
+
+// Scale returns m with every element multiplied by k
+func (m Matrix) Scale(k float64) (Matrix, error) {
+	result := Zero(m.Rows(), m.Cols())
+	for i, row := range m.rows {
+		for j, x := range row {
+			v, err := multiply.CheckedFloat(x, k)
+			if err != nil {
+				return Matrix{}, err
+			}
+			result.rows[i][j] = v
+		}
+	}
+	return result, nil
+}
+
+// Transpose returns m flipped over its diagonal, rows become columns: the element at i, j moves to j, i
+func (m Matrix) Transpose() Matrix {
+	result := Zero(m.Cols(), m.Rows())
+	for i, row := range m.rows {
+		for j, x := range row {
+			result.rows[j][i] = x
+		}
+	}
+	return result
+}
+
+// Determinant returns the determinant of a square matrix, it is 0 when the matrix is singular
+// It uses Gaussian elimination, which takes n^3 steps instead of the n! steps of expanding along a row
+func (m Matrix) Determinant() (float64, error) {
+	if m.Rows() != m.Cols() {
+		return 0, calculator.NewError("determinant", fmt.Errorf("%w: %dx%d is not square", ErrShape, m.Rows(), m.Cols()), m)
+	}
+
+	det := 1.0
+	a := m.Slice()
+	scale := largest(a)
+	for col := range a {
+		pivot := pivotRow(a, col)
+		if negligible(a[pivot][col], scale) {
+			return 0, nil
+		}
+		if pivot != col {
+			a[pivot], a[col] = a[col], a[pivot] // Swapping two rows changes the sign of the determinant
+			det = -det
+		}
+		det *= a[col][col]
+		eliminate(a, col, false)
+	}
+	return det, nil
+}
+
+// Inverse returns the matrix that gives the identity when multiplied by m, it fails with ErrSingular when there is none
+// It uses Gauss-Jordan elimination: the row operations that turn m into the identity turn the identity into the inverse
+func (m Matrix) Inverse() (Matrix, error) {
+	n := m.Rows()
+	if n != m.Cols() {
+		return Matrix{}, calculator.NewError("inverse", fmt.Errorf("%w: %dx%d is not square", ErrShape, n, m.Cols()), m)
+	}
+
+	// a is m with the identity on its right: [m | I]
+	a := make([][]float64, n)
+	for i, row := range m.rows {
+		a[i] = make([]float64, 2*n)
+		copy(a[i], row)
+		a[i][n+i] = 1
+	}
+
+	scale := largest(m.rows)
+	for col := 0; col < n; col++ {
+		pivot := pivotRow(a, col)
+		if negligible(a[pivot][col], scale) {
+			return Matrix{}, calculator.NewError("inverse", ErrSingular, m)
+		}
+		a[pivot], a[col] = a[col], a[pivot]
+
+		p := a[col][col]
+		for j := range a[col] {
+			a[col][j] /= p
+		}
+		eliminate(a, col, true)
+	}
+
+	inverse := Zero(n, n)
+	for i := range a {
+		copy(inverse.rows[i], a[i][n:])
+	}
+	return inverse, nil
+}
+
+// elementwise applies f to the elements of m and o at the same position
+func (m Matrix) elementwise(op string, o Matrix, f func(x, y float64) (float64, error)) (Matrix, error) {
+	if m.Rows() != o.Rows() || m.Cols() != o.Cols() {
+		return Matrix{}, calculator.NewError(op, fmt.Errorf("%w: %dx%d and %dx%d", ErrShape, m.Rows(), m.Cols(), o.Rows(), o.Cols()), m, o)
+	}
+
+	result := Zero(m.Rows(), m.Cols())
+	for i, row := range m.rows {
+		for j, x := range row {
+			v, err := f(x, o.rows[i][j])
+			if err != nil {
+				return Matrix{}, err
+			}
+			result.rows[i][j] = v
+		}
+	}
+	return result, nil
+}
+
+// largest returns the largest absolute value of the elements of a
+func largest(a [][]float64) float64 {
+	l := 0.0
+	for _, row := range a {
+		for _, x := range row {
+			l = math.Max(l, math.Abs(x))
+		}
+	}
+	return l
+}
+
+// negligible reports whether x is 0 next to scale, see Epsilon
+// It is true for every x when scale is 0, the matrix only has zeros then
+func negligible(x, scale float64) bool {
+	return math.Abs(x) <= Epsilon*scale
+}
+
+// pivotRow returns the row at or below col with the largest element in col
+// Dividing by the largest element keeps the rounding errors of floats small, this is called partial pivoting
+func pivotRow(a [][]float64, col int) int {
+	pivot := col
+	for i := col + 1; i < len(a); i++ {
+		if math.Abs(a[i][col]) > math.Abs(a[pivot][col]) {
+			pivot = i
+		}
+	}
+	return pivot
+}
+
+// eliminate subtracts multiples of row col from the rows below it, or from every other row when all is true,
+// so that they have 0 in col
+func eliminate(a [][]float64, col int, all bool) {
+	for i := range a {
+		if i == col || (!all && i < col) {
+			continue
+		}
+		factor := a[i][col] / a[col][col]
+		for j := range a[i] {
+			a[i][j] -= factor * a[col][j]
+		}
+	}
+}
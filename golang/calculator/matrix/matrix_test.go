@@ -0,0 +1,53 @@
+package matrix
+
+import (
+	"errors"
+	"math"
+	"testing"
+)
+
+func TestSingularIsRelativeToTheScale(t *testing.T) {
+	for _, tt := range []struct {
+		name     string
+		m        Mat2
+		singular bool
+	}{
+		{"identity", Mat2{{1, 0}, {0, 1}}, false},
+		{"small identity", Mat2{{1e-7, 0}, {0, 1e-7}}, false},
+		{"large identity", Mat2{{1e9, 0}, {0, 1e9}}, false},
+		{"dependent rows", Mat2{{1, 2}, {2, 4}}, true},
+		{"small dependent rows", Mat2{{1e-9, 2e-9}, {2e-9, 4e-9}}, true},
+		{"tiny pivot next to large elements", Mat2{{1e9, 1e9}, {1e9, 1e9 + 1e-6}}, true},
+		{"zero", Mat2{}, true},
+	} {
+		_, err := tt.m.Inverse()
+		if got := errors.Is(err, ErrSingular); got != tt.singular {
+			t.Errorf("%s: Mat2.Inverse err = %v, want singular: %v", tt.name, err, tt.singular)
+		}
+		_, err = tt.m.Matrix().Inverse()
+		if got := errors.Is(err, ErrSingular); got != tt.singular {
+			t.Errorf("%s: Matrix.Inverse err = %v, want singular: %v", tt.name, err, tt.singular)
+		}
+	}
+}
+
+func TestSmallScaleInverseAndDeterminant(t *testing.T) {
+	m := Mat2{{1e-7, 0}, {0, 1e-7}}
+	inverse, err := m.Inverse()
+	if err != nil {
+		t.Fatal(err)
+	}
+	product, identity := m.Multiply(inverse), Mat2{{1, 0}, {0, 1}}
+	for i := range product {
+		for j := range product[i] {
+			if math.Abs(product[i][j]-identity[i][j]) > 1e-12 {
+				t.Errorf("m * m^-1 = %v, want the identity", product)
+			}
+		}
+	}
+
+	det, err := m.Matrix().Determinant()
+	if err != nil || math.Abs(det-1e-14) > 1e-26 {
+		t.Errorf("Determinant() = %v, %v, want 1e-14", det, err)
+	}
+}
@@ -0,0 +1,283 @@
+// Package money implements Money, an amount in a currency like INR 1234.50
+// A float64 cannot hold 0.1 exactly, so adding paise as floats drifts: 0.1 + 0.2 is 0.30000000000000004
+// Money counts whole minor units instead, paise for INR and cents for USD, in an int64, so every amount is exact
+//
+// Like rational.Rational, the fields are unexported and every operation is checked: an amount that does not fit into
+// an int64, or two amounts in different currencies, is an error instead of a wrong result
+package money
+
+import (
+	"cmp"
+	"errors"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+	"github.com/shashank-priyadarshi/training/calculator/add"
+	"github.com/shashank-priyadarshi/training/calculator/divide"
+	"github.com/shashank-priyadarshi/training/calculator/multiply"
+	"github.com/shashank-priyadarshi/training/calculator/scientific"
+	"github.com/shashank-priyadarshi/training/calculator/subtract"
+)
+
+// ErrCurrency is returned for a currency that is not known, and for an operation on amounts in different currencies
+var ErrCurrency = errors.New("currency")
+
+// Currency is an ISO 4217 code like INR
+type Currency string
+
+const (
+	INR Currency = "INR"
+	USD Currency = "USD"
+	EUR Currency = "EUR"
+	GBP Currency = "GBP"
+	JPY Currency = "JPY"
+)
+
+// minorDigits is the number of digits after the point of every currency: 1 rupee is 100 paise, a yen has no smaller unit
+var minorDigits = map[Currency]int{INR: 2, USD: 2, EUR: 2, GBP: 2, JPY: 0}
+
+// Digits returns the number of digits after the point, e.g 2 for INR
+func (c Currency) Digits() (int, error) {
+	d, ok := minorDigits[c]
+	if !ok {
+		return 0, fmt.Errorf("%w: unknown currency %q", ErrCurrency, string(c))
+	}
+	return d, nil
+}
+
+// Money is an amount of minor units in a currency, INR 1234.50 is 123450 paise
+// The zero value is 0 without a currency, it can be added to an amount in any currency, which is handy for totals
+type Money struct {
+	amount   int64 // Minor units
+	currency Currency
+}
+
+// New returns minor units of the currency: New(123450, INR) is INR 1234.50
+func New(minor int64, c Currency) (Money, error) {
+	if _, err := c.Digits(); err != nil {
+		return Money{}, err
+	}
+	return Money{amount: minor, currency: c}, nil
+}
+
+// FromMajor returns whole units of the currency: FromMajor(1234, INR) is INR 1234.00
+func FromMajor(major int64, c Currency) (Money, error) {
+	d, err := c.Digits()
+	if err != nil {
+		return Money{}, err
+	}
+	scale, err := scientific.PowInt(int64(10), int64(d))
+	if err != nil {
+		return Money{}, err
+	}
+	minor, err := multiply.Checked(major, scale)
+	if err != nil {
+		return Money{}, calculator.NewError("money", calculator.ErrOverflow, major, c)
+	}
+	return Money{amount: minor, currency: c}, nil
+}
+
+// Parse reads a currency code and an amount like INR 1234.50, the amount has at most as many digits after the point
+// as the currency: INR 0.5 is 50 paise, INR 0.555 is an error
+func Parse(s string) (Money, error) {
+	code, amount, ok := strings.Cut(strings.TrimSpace(s), " ")
+	if !ok {
+		return Money{}, fmt.Errorf("%w: %q is not an amount like INR 1234.50", calculator.ErrInvalidOperand, s)
+	}
+	c := Currency(strings.ToUpper(code))
+	d, err := c.Digits()
+	if err != nil {
+		return Money{}, err
+	}
+
+	amount = strings.TrimSpace(amount)
+	negative := strings.HasPrefix(amount, "-")
+	amount = strings.TrimPrefix(amount, "-")
+	whole, fraction, _ := strings.Cut(amount, ".")
+	if whole == "" || strings.ContainsAny(amount, "+-") || len(fraction) > d {
+		return Money{}, fmt.Errorf("%w: %q is not an amount of %s with at most %d digits after the point", calculator.ErrInvalidOperand, s, c, d)
+	}
+
+	// 1234.5 is 123450 paise, the fraction is filled up with zeros to the number of digits of the currency
+	// The sign is parsed with the digits, the smallest int64 has no positive counterpart to negate
+	digits := whole + fraction + strings.Repeat("0", d-len(fraction))
+	if negative {
+		digits = "-" + digits
+	}
+	minor, err := strconv.ParseInt(digits, 10, 64)
+	if errors.Is(err, strconv.ErrRange) {
+		return Money{}, fmt.Errorf("%w: %q does not fit into an int64 of minor units", calculator.ErrOverflow, s)
+	}
+	if err != nil {
+		return Money{}, fmt.Errorf("%w: %q is not an amount like INR 1234.50", calculator.ErrInvalidOperand, s)
+	}
+	return Money{amount: minor, currency: c}, nil
+}
+
+// Minor returns the amount in minor units, e.g 123450 for INR 1234.50
+func (m Money) Minor() int64 {
+	return m.amount
+}
+
+// Currency returns the currency, it is empty for the zero value
+func (m Money) Currency() Currency {
+	return m.currency
+}
+
+// IsZero reports whether the amount is 0, in any currency
+func (m Money) IsZero() bool {
+	return m.amount == 0
+}
+
+// Sign returns -1, 0 or 1 for a negative, zero or positive amount
+func (m Money) Sign() int {
+	return cmp.Compare(m.amount, 0)
+}
+
+// String writes the currency and the amount with the digits of the currency, e.g INR 1234.50 or JPY 500
+// The zero value has no currency and is written as 0
+func (m Money) String() string {
+	if m.currency == "" {
+		return strconv.FormatInt(m.amount, 10)
+	}
+	d, err := m.currency.Digits()
+	if err != nil || d == 0 {
+		return fmt.Sprintf("%s %d", m.currency, m.amount)
+	}
+
+	// The smallest int64 has no positive counterpart, as a uint64 it does
+	sign, abs := "", uint64(m.amount)
+	if m.amount < 0 {
+		sign, abs = "-", -abs
+	}
+	scale := uint64(1)
+	for i := 0; i < d; i++ {
+		scale *= 10
+	}
+	return fmt.Sprintf("%s %s%d.%0*d", m.currency, sign, abs/scale, d, abs%scale)
+}
+
+// Add returns m + o, both must be in the same currency
+func (m Money) Add(o Money) (Money, error) {
+	c, err := m.common(o)
+	if err != nil {
+		return Money{}, err
+	}
+	amount, err := add.Checked(m.amount, o.amount)
+	if err != nil {
+		return Money{}, calculator.NewError("add", calculator.ErrOverflow, m, o)
+	}
+	return Money{amount: amount, currency: c}, nil
+}
+
+// Subtract returns m - o, both must be in the same currency
+func (m Money) Subtract(o Money) (Money, error) {
+	c, err := m.common(o)
+	if err != nil {
+		return Money{}, err
+	}
+	amount, err := subtract.Checked(m.amount, o.amount)
+	if err != nil {
+		return Money{}, calculator.NewError("subtract", calculator.ErrOverflow, m, o)
+	}
+	return Money{amount: amount, currency: c}, nil
+}
+
+// Multiply returns m * n, e.g the cost of n items
+func (m Money) Multiply(n int64) (Money, error) {
+	amount, err := multiply.Checked(m.amount, n)
+	if err != nil {
+		return Money{}, calculator.NewError("multiply", calculator.ErrOverflow, m, n)
+	}
+	return Money{amount: amount, currency: m.currency}, nil
+}
+
+// Rate returns basisPoints hundredths of a percent of m, 750 is 7.5%: INR 1000.00 at 750 is INR 75.00
+// A result between two minor units is rounded to the nearest one, halves away from zero
+func (m Money) Rate(basisPoints int64) (Money, error) {
+	p, err := multiply.Checked(m.amount, basisPoints)
+	if err != nil {
+		return Money{}, calculator.NewError("rate", calculator.ErrOverflow, m, basisPoints)
+	}
+	q, r, err := divide.QuoRem(p, 10_000)
+	if err != nil {
+		return Money{}, err
+	}
+	// q is at most the largest int64 divided by 10000, one more cannot overflow
+	switch {
+	case r >= 5_000:
+		q++
+	case r <= -5_000:
+		q--
+	}
+	return Money{amount: q, currency: m.currency}, nil
+}
+
+// Split divides m into n parts that add up to m exactly, the minor units left over go to the first parts:
+// INR 100.00 split by 3 is INR 33.34, INR 33.33 and INR 33.33
+func (m Money) Split(n int) ([]Money, error) {
+	if n <= 0 {
+		return nil, calculator.NewError("split", calculator.ErrInvalidOperand, m, n)
+	}
+	q, r, err := divide.QuoRem(m.amount, int64(n))
+	if err != nil {
+		return nil, err
+	}
+
+	one := int64(1) // r has the sign of m, a negative amount gives out -1s
+	if r < 0 {
+		one, r = -1, -r
+	}
+	parts := make([]Money, n)
+	for i := range parts {
+		parts[i] = Money{amount: q, currency: m.currency}
+		if int64(i) < r {
+			parts[i].amount += one
+		}
+	}
+	return parts, nil
+}
+
+// Compare returns -1, 0 or 1 when m is less than, equal to or more than o, both must be in the same currency
+func (m Money) Compare(o Money) (int, error) {
+	if _, err := m.common(o); err != nil {
+		return 0, err
+	}
+	return cmp.Compare(m.amount, o.amount), nil
+}
+
+// common returns the currency of m and o, the zero value takes the currency of the other amount
+func (m Money) common(o Money) (Currency, error) {
+	switch {
+	case m.currency == o.currency || o.currency == "":
+		return m.currency, nil
+	case m.currency == "":
+		return o.currency, nil
+	}
+	return "", fmt.Errorf("%w: %s and %s are different currencies", ErrCurrency, m.currency, o.currency)
+}
+
+// MarshalText writes the amount like String, so encoding/json writes "INR 1234.50"
+// The zero value is written as an empty string
+func (m Money) MarshalText() ([]byte, error) {
+	if m.currency == "" {
+		return []byte{}, nil
+	}
+	return []byte(m.String()), nil
+}
+
+// UnmarshalText reads an amount written by MarshalText, it has a pointer receiver because it changes m
+func (m *Money) UnmarshalText(text []byte) error {
+	if len(text) == 0 {
+		*m = Money{}
+		return nil
+	}
+	money, err := Parse(string(text))
+	if err != nil {
+		return err
+	}
+	*m = money
+	return nil
+}
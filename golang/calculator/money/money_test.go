@@ -0,0 +1,196 @@
+package money
+
+import (
+	"encoding/json"
+	"errors"
+	"math"
+	"testing"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+)
+
+// inr returns paise in INR
+func inr(paise int64) Money {
+	return Money{amount: paise, currency: INR}
+}
+
+func TestParseAndString(t *testing.T) {
+	for _, tt := range []struct {
+		text string
+		want Money
+		str  string // What String writes, the digits of the currency are filled up
+	}{
+		{"INR 1234.50", inr(123450), "INR 1234.50"},
+		{"INR 1234.5", inr(123450), "INR 1234.50"},
+		{"inr 1234", inr(123400), "INR 1234.00"},
+		{"INR 0.05", inr(5), "INR 0.05"},
+		{"INR -0.05", inr(-5), "INR -0.05"},
+		{"INR -1234.56", inr(-123456), "INR -1234.56"},
+		{"JPY 500", Money{amount: 500, currency: JPY}, "JPY 500"},
+		{"JPY -500", Money{amount: -500, currency: JPY}, "JPY -500"},
+		{"INR 92233720368547758.07", inr(math.MaxInt64), "INR 92233720368547758.07"},
+		// The smallest int64 has no positive counterpart, it is read and written all the same
+		{"INR -92233720368547758.08", inr(math.MinInt64), "INR -92233720368547758.08"},
+	} {
+		got, err := Parse(tt.text)
+		if err != nil || got != tt.want {
+			t.Errorf("Parse(%q) = %v, %v, want %v", tt.text, got, err, tt.want)
+			continue
+		}
+		if got.String() != tt.str {
+			t.Errorf("Parse(%q).String() = %q, want %q", tt.text, got.String(), tt.str)
+		}
+	}
+
+	if s := (Money{}).String(); s != "0" {
+		t.Errorf("zero value String() = %q, want 0", s)
+	}
+}
+
+func TestParseErrors(t *testing.T) {
+	for _, tt := range []struct {
+		text string
+		want error
+	}{
+		{"1234.50", calculator.ErrInvalidOperand},
+		{"XYZ 12", ErrCurrency},
+		{"INR 0.555", calculator.ErrInvalidOperand},
+		{"JPY 500.5", calculator.ErrInvalidOperand},
+		{"INR", calculator.ErrInvalidOperand},
+		{"INR -", calculator.ErrInvalidOperand},
+		{"INR --5", calculator.ErrInvalidOperand},
+		{"INR +5", calculator.ErrInvalidOperand},
+		{"INR 12a", calculator.ErrInvalidOperand},
+		{"INR 92233720368547758.08", calculator.ErrOverflow},
+		{"INR -92233720368547758.09", calculator.ErrOverflow},
+	} {
+		if _, err := Parse(tt.text); !errors.Is(err, tt.want) {
+			t.Errorf("Parse(%q) err = %v, want %v", tt.text, err, tt.want)
+		}
+	}
+}
+
+func TestFromMajor(t *testing.T) {
+	if m, err := FromMajor(500, JPY); err != nil || m.Minor() != 500 {
+		t.Errorf("FromMajor(500, JPY) = %v, %v, want 500 yen, a yen has no minor unit", m, err)
+	}
+	if m, err := FromMajor(-12, INR); err != nil || m.Minor() != -1200 {
+		t.Errorf("FromMajor(-12, INR) = %v, %v, want -1200 paise", m, err)
+	}
+	if _, err := FromMajor(math.MaxInt64/10, INR); !errors.Is(err, calculator.ErrOverflow) {
+		t.Errorf("FromMajor(MaxInt64/10, INR) err = %v, want ErrOverflow", err)
+	}
+	if _, err := FromMajor(1, "XYZ"); !errors.Is(err, ErrCurrency) {
+		t.Errorf("FromMajor(1, XYZ) err = %v, want ErrCurrency", err)
+	}
+}
+
+func TestArithmetic(t *testing.T) {
+	usd := Money{amount: 100, currency: USD}
+	for _, tt := range []struct {
+		name string
+		got  func() (Money, error)
+		want Money
+		err  error
+	}{
+		{"add", func() (Money, error) { return inr(150).Add(inr(-200)) }, inr(-50), nil},
+		{"subtract", func() (Money, error) { return inr(150).Subtract(inr(200)) }, inr(-50), nil},
+		{"zero value takes the currency", func() (Money, error) { return Money{}.Add(inr(5)) }, inr(5), nil},
+		{"multiply", func() (Money, error) { return inr(-250).Multiply(3) }, inr(-750), nil},
+		{"different currencies", func() (Money, error) { return inr(1).Add(usd) }, Money{}, ErrCurrency},
+		{"add overflow", func() (Money, error) { return inr(math.MaxInt64).Add(inr(1)) }, Money{}, calculator.ErrOverflow},
+		{"subtract overflow", func() (Money, error) { return inr(math.MinInt64).Subtract(inr(1)) }, Money{}, calculator.ErrOverflow},
+		{"multiply overflow", func() (Money, error) { return inr(math.MaxInt64 / 2).Multiply(3) }, Money{}, calculator.ErrOverflow},
+		{"rate overflow", func() (Money, error) { return inr(math.MaxInt64 / 100).Rate(10_000) }, Money{}, calculator.ErrOverflow},
+	} {
+		got, err := tt.got()
+		if got != tt.want || !errors.Is(err, tt.err) {
+			t.Errorf("%s = %v, %v, want %v, %v", tt.name, got, err, tt.want, tt.err)
+		}
+	}
+
+	if _, err := inr(1).Compare(usd); !errors.Is(err, ErrCurrency) {
+		t.Errorf("Compare of INR and USD err = %v, want ErrCurrency", err)
+	}
+}
+
+func TestRate(t *testing.T) {
+	for _, tt := range []struct {
+		amount, basisPoints, want int64
+	}{
+		{100_000, 750, 7_500},
+		{100_000, -750, -7_500},
+		// 50% of 1 paisa is half a paisa, halves are rounded away from zero
+		{1, 5_000, 1},
+		{-1, 5_000, -1},
+		{1, -5_000, -1},
+		{1, 4_999, 0},
+		{-1, 4_999, 0},
+		{3, 5_000, 2},
+		{-3, 5_000, -2},
+		// 0.15% of 1000 paise is 1.5 paise
+		{1_000, 15, 2},
+		{1_000, 14, 1},
+	} {
+		got, err := inr(tt.amount).Rate(tt.basisPoints)
+		if err != nil || got != inr(tt.want) {
+			t.Errorf("Rate(%d) of %d paise = %v, %v, want %d paise", tt.basisPoints, tt.amount, got, err, tt.want)
+		}
+	}
+}
+
+func TestSplit(t *testing.T) {
+	for _, tt := range []struct {
+		amount int64
+		n      int
+		want   []int64
+	}{
+		{10_000, 3, []int64{3_334, 3_333, 3_333}},
+		{-10_000, 3, []int64{-3_334, -3_333, -3_333}},
+		{2, 3, []int64{1, 1, 0}},
+		{-2, 3, []int64{-1, -1, 0}},
+		{0, 2, []int64{0, 0}},
+		{math.MinInt64, 1, []int64{math.MinInt64}},
+	} {
+		parts, err := inr(tt.amount).Split(tt.n)
+		if err != nil || len(parts) != len(tt.want) {
+			t.Errorf("Split(%d) of %d paise = %v, %v, want %v", tt.n, tt.amount, parts, err, tt.want)
+			continue
+		}
+		for i, w := range tt.want {
+			if parts[i] != inr(w) {
+				t.Errorf("Split(%d) of %d paise = %v, want %v", tt.n, tt.amount, parts, tt.want)
+				break
+			}
+		}
+	}
+
+	for _, n := range []int{0, -1} {
+		if _, err := inr(100).Split(n); !errors.Is(err, calculator.ErrInvalidOperand) {
+			t.Errorf("Split(%d) err = %v, want ErrInvalidOperand", n, err)
+		}
+	}
+}
+
+func TestJSON(t *testing.T) {
+	type payslip struct {
+		Gross Money `json:"gross"`
+		Bonus Money `json:"bonus"`
+	}
+	data, err := json.Marshal(payslip{Gross: inr(123450)})
+	if err != nil {
+		t.Fatal(err)
+	}
+	// The zero value is written as an empty string
+	if want := `{"gross":"INR 1234.50","bonus":""}`; string(data) != want {
+		t.Errorf("json.Marshal = %s, want %s", data, want)
+	}
+
+	var got payslip
+	if err := json.Unmarshal(data, &got); err != nil || got != (payslip{Gross: inr(123450)}) {
+		t.Errorf("json.Unmarshal(%s) = %+v, %v, want the payslip back", data, got, err)
+	}
+	if err := json.Unmarshal([]byte(`{"gross":"INR 1.234"}`), &got); !errors.Is(err, calculator.ErrInvalidOperand) {
+		t.Errorf("json.Unmarshal of INR 1.234 err = %v, want ErrInvalidOperand", err)
+	}
+}
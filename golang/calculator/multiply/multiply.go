@@ -1,5 +1,70 @@
 package multiply
 
-func Multiply(a, b int) int {
+import "github.com/shashank-priyadarshi/training/calculator"
+
+// Multiply works for every integer and floating-point type
+func Multiply[T calculator.Number](a, b T) T {
+	return a * b
+}
+
+// Checked multiplies a and b, but returns calculator.ErrOverflow instead of silently wrapping around
+// It works for every integer type: Checked[uint8](16, 16) fails because 256 does not fit into a uint8
+func Checked[T calculator.Integer](a, b T) (T, error) {
+	if a == 0 || b == 0 {
+		return 0, nil
+	}
+	c := a * b
+	// Dividing the result by one operand must give back the other operand, otherwise the result has wrapped around
+	// The smallest signed value is the exception: lo * -1 wraps around to lo, and lo / -1 is lo again
+	if c/b != a || (calculator.IsSigned[T]() && minTimesMinusOne(a, b)) {
+		return 0, calculator.NewError("multiply", calculator.ErrOverflow, a, b)
+	}
+	return c, nil
+}
+
+// minTimesMinusOne reports whether one operand is -1 and the other is the smallest value of T
+func minTimesMinusOne[T calculator.Integer](a, b T) bool {
+	lo, _ := calculator.Limits[T]()
+	minusOne := ^T(0) // All bits set is -1 for signed types
+	return (a == minusOne && b == lo) || (b == minusOne && a == lo)
+}
+
+// Saturating multiplies a and b, stopping at the largest or the smallest value of T: Saturating[uint8](16, 16) is 255
+func Saturating[T calculator.Integer](a, b T) T {
+	c, err := Checked(a, b)
+	if err == nil {
+		return c
+	}
+	lo, hi := calculator.Limits[T]()
+	// The result is negative when exactly one of the operands is negative
+	if (a < 0) != (b < 0) {
+		return lo
+	}
+	return hi
+}
+
+// Wrapping multiplies a and b, wrapping around like the * operator does: Wrapping[uint8](16, 16) is 0
+func Wrapping[T calculator.Integer](a, b T) T {
 	return a * b
 }
+
+// WithMode multiplies a and b, overflow decides between Checked, Saturating and Wrapping
+func WithMode[T calculator.Integer](a, b T, overflow calculator.Overflow) (T, error) {
+	switch overflow {
+	case calculator.OverflowSaturate:
+		return Saturating(a, b), nil
+	case calculator.OverflowWrap:
+		return Wrapping(a, b), nil
+	}
+	return Checked(a, b)
+}
+
+// CheckedFloat multiplies a and b, returning an error for NaN or infinite operands, or when the result becomes infinite
+func CheckedFloat[T calculator.Float](a, b T) (T, error) {
+	return calculator.CheckFloat("multiply", a*b, a, b)
+}
+
+// CheckedComplex multiplies complex numbers like CheckedFloat, the real and the imaginary parts are checked
+func CheckedComplex[T calculator.Complex](a, b T) (T, error) {
+	return calculator.CheckComplex("multiply", a*b, a, b)
+}
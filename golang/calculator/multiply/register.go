@@ -0,0 +1,19 @@
+package multiply
+
+import "github.com/shashank-priyadarshi/training/calculator"
+
+func init() {
+	calculator.Register(calculator.Func{
+		Spec: calculator.Info{
+			Name:        "multiply",
+			Symbol:      "*",
+			Arity:       2,
+			Precedence:  calculator.PrecMultiplicative,
+			Description: "a * b multiplies a by b",
+		},
+		Int:     calculator.Binary(WithMode[int64]),
+		Uint:    calculator.Binary(WithMode[uint64]),
+		Float:   calculator.FloatBinary(CheckedFloat[float64]),
+		Complex: calculator.ComplexBinary(CheckedComplex[complex128]),
+	})
+}
@@ -0,0 +1,158 @@
+package calculator
+
+import (
+	"fmt"
+	"math"
+	"math/big"
+	"math/cmplx"
+	"strconv"
+	"strings"
+	"unsafe"
+)
+
+// Constraints for the type parameters of the calculator functions
+// ~int means int and every type defined on top of it, like type DayOfMonth int
+type (
+	Signed   interface{ ~int | ~int8 | ~int16 | ~int32 | ~int64 }
+	Unsigned interface{ ~uint | ~uint8 | ~uint16 | ~uint32 | ~uint64 | ~uintptr }
+	Integer  interface{ Signed | Unsigned }
+	Float    interface{ ~float32 | ~float64 }
+	Number   interface{ Integer | Float }
+	Complex  interface{ ~complex64 | ~complex128 } // Not a Number, complex numbers cannot be compared with < or >
+)
+
+// CheckFloat is used by the CheckedFloat functions of the calculator packages
+// It returns ErrInvalidOperand when a or b is NaN or infinite, and ErrOverflow when the result became infinite
+func CheckFloat[T Float](op string, result, a, b T) (T, error) {
+	for _, operand := range []float64{float64(a), float64(b)} {
+		if math.IsNaN(operand) || math.IsInf(operand, 0) {
+			return 0, NewError(op, ErrInvalidOperand, a, b)
+		}
+	}
+	if r := float64(result); math.IsNaN(r) || math.IsInf(r, 0) {
+		return 0, NewError(op, ErrOverflow, a, b)
+	}
+	return result, nil
+}
+
+// CheckComplex works like CheckFloat for complex numbers, where the real or the imaginary part can be NaN or infinite
+func CheckComplex[T Complex](op string, result, a, b T) (T, error) {
+	for _, operand := range []complex128{complex128(a), complex128(b)} {
+		if cmplx.IsNaN(operand) || cmplx.IsInf(operand) {
+			return 0, NewError(op, ErrInvalidOperand, a, b)
+		}
+	}
+	if r := complex128(result); cmplx.IsNaN(r) || cmplx.IsInf(r) {
+		return 0, NewError(op, ErrOverflow, a, b)
+	}
+	return result, nil
+}
+
+// ParseFloat reads a number literal into a float64: a decimal number like 1.5e3, a hexadecimal float like 0x1p-2,
+// or an integer with a base prefix like 0xff, 0o17 or 0b1010
+// Prefixed integers are not limited to int64, 0xffffffffffffffff is 1.8446744073709552e+19 like its decimal form
+// The errors are *strconv.NumError, strconv.ErrRange when the number does not fit into a float64
+func ParseFloat(text string) (float64, error) {
+	digits := strings.TrimLeft(text, "+-")
+	prefixed := len(digits) > 2 && digits[0] == '0' && strings.ContainsRune("xXoObB", rune(digits[1]))
+	// p is not a hexadecimal digit, it starts the binary exponent of a hexadecimal float, which strconv reads
+	if !prefixed || strings.ContainsAny(digits, "pP") {
+		return strconv.ParseFloat(text, 64)
+	}
+
+	i, ok := new(big.Int).SetString(text, 0)
+	if !ok {
+		return 0, &strconv.NumError{Func: "ParseFloat", Num: text, Err: strconv.ErrSyntax}
+	}
+	f, _ := new(big.Float).SetInt(i).Float64() // Rounded to the nearest float64 like a decimal literal
+	if math.IsInf(f, 0) {
+		return f, &strconv.NumError{Func: "ParseFloat", Num: text, Err: strconv.ErrRange}
+	}
+	return f, nil
+}
+
+// IsSigned reports whether T can hold negative values
+func IsSigned[T Integer]() bool {
+	var zero T
+	return ^zero < 0 // All bits set is -1 for signed types, and the largest value for unsigned types
+}
+
+// Bits returns the size of T in bits, e.g 8 for int8 and uint8
+func Bits[T Integer]() int {
+	var zero T
+	return int(unsafe.Sizeof(zero)) * 8
+}
+
+// Limits returns the smallest and the largest value of T, e.g -128 and 127 for int8, 0 and 255 for uint8
+func Limits[T Integer]() (lo, hi T) {
+	var zero T
+	if !IsSigned[T]() {
+		return 0, ^zero
+	}
+	// Only the sign bit set is the smallest value, every bit except the sign bit set is the largest value
+	lo = T(1) << (Bits[T]() - 1)
+	return lo, ^lo
+}
+
+// Overflow decides what happens when the result of an integer operation does not fit into its type
+type Overflow int
+
+const (
+	OverflowError    Overflow = iota // Return ErrOverflow, this is what the Checked functions do
+	OverflowSaturate                 // Stop at the smallest or the largest value of the type, like the Saturating functions
+	OverflowWrap                     // Wrap around like the Go operators do, like the Wrapping functions
+)
+
+var overflowNames = map[Overflow]string{
+	OverflowError:    "error",
+	OverflowSaturate: "saturate",
+	OverflowWrap:     "wrap",
+}
+
+func (o Overflow) String() string {
+	if name, ok := overflowNames[o]; ok {
+		return name
+	}
+	return fmt.Sprintf("Overflow(%d)", int(o))
+}
+
+// ParseOverflow returns the Overflow with the given name: error, saturate or wrap
+func ParseOverflow(name string) (Overflow, error) {
+	for o, n := range overflowNames {
+		if n == name {
+			return o, nil
+		}
+	}
+	return 0, fmt.Errorf("unknown overflow %q, expected error, saturate or wrap", name)
+}
+
+// NarrowInt converts v to the signed type T, overflow decides what happens when v does not fit into T
+// Operations compute with int64, and the result is narrowed to the type of the operands, e.g int8
+func NarrowInt[T Integer](op string, v int64, overflow Overflow) (T, error) {
+	lo, hi := Limits[T]()
+	switch {
+	case v >= int64(lo) && v <= int64(hi):
+		return T(v), nil
+	case overflow == OverflowWrap:
+		return T(v), nil
+	case overflow == OverflowSaturate && v < int64(lo):
+		return lo, nil
+	case overflow == OverflowSaturate:
+		return hi, nil
+	}
+	return 0, NewError(op, ErrOverflow, fmt.Sprintf("%d does not fit into %T", v, lo))
+}
+
+// NarrowUint converts v to the unsigned type T, overflow decides what happens when v does not fit into T
+func NarrowUint[T Integer](op string, v uint64, overflow Overflow) (T, error) {
+	_, hi := Limits[T]()
+	switch {
+	case v <= uint64(hi):
+		return T(v), nil
+	case overflow == OverflowWrap:
+		return T(v), nil
+	case overflow == OverflowSaturate:
+		return hi, nil
+	}
+	return 0, NewError(op, ErrOverflow, fmt.Sprintf("%d does not fit into %T", v, hi))
+}
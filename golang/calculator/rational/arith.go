@@ -0,0 +1,48 @@
+package rational
+
+import "fmt"
+
+// Arith evaluates expressions with Rational, selected with the rat mode of the expr package
+// Fractions are exact like with bigrat, but results that do not fit into int64 fail with calculator.ErrOverflow
+type Arith struct{}
+
+func (Arith) Name() string {
+	return "rat"
+}
+
+func (Arith) Literal(text string) (Rational, error) {
+	return Parse(text)
+}
+
+func (Arith) Apply(op string, args ...Rational) (Rational, error) {
+	if len(args) == 1 {
+		switch op {
+		case "plus":
+			return args[0], nil
+		case "negate":
+			return args[0].Negate()
+		}
+		return Rational{}, unsupported(op, len(args))
+	}
+
+	x, y := args[0], args[1]
+	switch op {
+	case "add":
+		return x.Add(y)
+	case "subtract":
+		return x.Subtract(y)
+	case "multiply":
+		return x.Multiply(y)
+	case "divide":
+		return x.Divide(y)
+	}
+	return Rational{}, unsupported(op, len(args))
+}
+
+func (Arith) Format(v Rational) string {
+	return v.String()
+}
+
+func unsupported(op string, arity int) error {
+	return fmt.Errorf("%s with %d operands is not available in rat mode", op, arity)
+}
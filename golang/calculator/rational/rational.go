@@ -0,0 +1,215 @@
+// Package rational implements Rational, an exact fraction like 1/2 or -3/4 made of two int64s
+// divide.Divide(1, 2) drops the fraction and gives 0, a float64 gives 0.5 but cannot hold 1/3 exactly, a Rational gives 1/2 and 1/3
+//
+// Rational is also an example of a struct with methods: its fields are unexported, so the only way to create one
+// is through New or Parse, which always normalize it, and the methods can rely on that
+// Unlike big.Rat from math/big, a Rational is a value: methods return a new Rational and never change the receiver
+package rational
+
+import (
+	"errors"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+	"github.com/shashank-priyadarshi/training/calculator/add"
+	"github.com/shashank-priyadarshi/training/calculator/multiply"
+	"github.com/shashank-priyadarshi/training/calculator/scientific"
+	"github.com/shashank-priyadarshi/training/calculator/subtract"
+)
+
+// Rational is the fraction num/den, always normalized: den is positive and num and den have no common divisor, so 2/4 is 1/2
+// The zero value is 0
+type Rational struct {
+	num int64
+	den int64 // 0 only in the zero value, where it means 1
+}
+
+// New returns num/den normalized, e.g New(2, -4) is -1/2
+// It fails with calculator.ErrDivisionByZero when den is 0
+func New(num, den int64) (Rational, error) {
+	if den == 0 {
+		return Rational{}, calculator.NewError("rational", calculator.ErrDivisionByZero, num, den)
+	}
+
+	g, err := scientific.GCD(num, den)
+	if err != nil {
+		return Rational{}, calculator.NewError("rational", calculator.ErrOverflow, num, den)
+	}
+	n, d := num/g, den/g
+
+	if d < 0 {
+		// The smallest int64 has no positive counterpart, so the sign cannot always move to the numerator
+		n, err = subtract.Checked(0, n)
+		if err == nil {
+			d, err = subtract.Checked(0, d)
+		}
+		if err != nil {
+			return Rational{}, calculator.NewError("rational", calculator.ErrOverflow, num, den)
+		}
+	}
+	return Rational{num: n, den: d}, nil
+}
+
+// FromInt returns n/1
+func FromInt(n int64) Rational {
+	return Rational{num: n, den: 1}
+}
+
+// Parse reads a fraction like 3/4 or -3/4, a whole number like 7, or a decimal like 0.75, which is 3/4
+func Parse(s string) (Rational, error) {
+	s = strings.TrimSpace(s)
+	if num, den, ok := strings.Cut(s, "/"); ok {
+		n, err1 := strconv.ParseInt(strings.TrimSpace(num), 10, 64)
+		d, err2 := strconv.ParseInt(strings.TrimSpace(den), 10, 64)
+		if errors.Is(err1, strconv.ErrSyntax) || errors.Is(err2, strconv.ErrSyntax) {
+			return Rational{}, fmt.Errorf("%w: %s is not a fraction like 3/4", calculator.ErrInvalidOperand, s)
+		}
+		// What is left is strconv.ErrRange, the digits are fine but too many for an int64
+		if err1 != nil || err2 != nil {
+			return Rational{}, fmt.Errorf("%w: %s does not fit into an int64 fraction", calculator.ErrOverflow, s)
+		}
+		return New(n, d)
+	}
+
+	// 0.75 is 075/100, the digits after the point decide the power of 10 of the denominator
+	whole, fraction, _ := strings.Cut(s, ".")
+	n, err := strconv.ParseInt(whole+fraction, 10, 64)
+	if errors.Is(err, strconv.ErrSyntax) || strings.ContainsAny(fraction, "+-") {
+		return Rational{}, fmt.Errorf("%w: %s is not a number like 7, 0.75 or 3/4", calculator.ErrInvalidOperand, s)
+	}
+	d, err2 := scientific.PowInt(int64(10), int64(len(fraction)))
+	if err != nil || err2 != nil {
+		return Rational{}, fmt.Errorf("%w: %s has too many digits", calculator.ErrOverflow, s)
+	}
+	return New(n, d)
+}
+
+// Num returns the numerator, it carries the sign
+func (r Rational) Num() int64 {
+	return r.num
+}
+
+// Den returns the denominator, it is always positive
+func (r Rational) Den() int64 {
+	if r.den == 0 {
+		return 1
+	}
+	return r.den
+}
+
+// Float64 returns the nearest float64, e.g 0.3333333333333333 for 1/3
+func (r Rational) Float64() float64 {
+	return float64(r.num) / float64(r.Den())
+}
+
+// String writes whole numbers without a denominator: 2 instead of 2/1
+func (r Rational) String() string {
+	if r.Den() == 1 {
+		return strconv.FormatInt(r.num, 10)
+	}
+	return fmt.Sprintf("%d/%d", r.num, r.Den())
+}
+
+// Add returns r + o, a/b + c/d is computed over the least common multiple of b and d to delay overflows
+func (r Rational) Add(o Rational) (Rational, error) {
+	a, b, err := r.common(o)
+	if err != nil {
+		return Rational{}, calculator.NewError("add", calculator.ErrOverflow, r, o)
+	}
+	num, err := add.Checked(a.num, b.num)
+	if err != nil {
+		return Rational{}, calculator.NewError("add", calculator.ErrOverflow, r, o)
+	}
+	return New(num, a.den)
+}
+
+// Subtract returns r - o
+func (r Rational) Subtract(o Rational) (Rational, error) {
+	a, b, err := r.common(o)
+	if err != nil {
+		return Rational{}, calculator.NewError("subtract", calculator.ErrOverflow, r, o)
+	}
+	num, err := subtract.Checked(a.num, b.num)
+	if err != nil {
+		return Rational{}, calculator.NewError("subtract", calculator.ErrOverflow, r, o)
+	}
+	return New(num, a.den)
+}
+
+// Multiply returns r * o, common divisors are removed before multiplying: 2/3 * 3/4 is 1/1 * 1/2
+func (r Rational) Multiply(o Rational) (Rational, error) {
+	v, err := mul(r.num, r.Den(), o.num, o.Den())
+	if err != nil {
+		return Rational{}, calculator.NewError("multiply", calculator.ErrOverflow, r, o)
+	}
+	return v, nil
+}
+
+// Divide returns r / o, which is r multiplied by o upside down
+// It fails with calculator.ErrDivisionByZero when o is 0
+func (r Rational) Divide(o Rational) (Rational, error) {
+	if o.num == 0 {
+		return Rational{}, calculator.NewError("divide", calculator.ErrDivisionByZero, r, o)
+	}
+	v, err := mul(r.num, r.Den(), o.Den(), o.num)
+	if err != nil {
+		return Rational{}, calculator.NewError("divide", calculator.ErrOverflow, r, o)
+	}
+	return v, nil
+}
+
+// Negate returns -r, it fails when the numerator is the smallest int64
+func (r Rational) Negate() (Rational, error) {
+	num, err := subtract.Checked(0, r.num)
+	if err != nil {
+		return Rational{}, calculator.NewError("negate", calculator.ErrOverflow, r)
+	}
+	return Rational{num: num, den: r.Den()}, nil
+}
+
+// common returns r and o with the same denominator, which is not normalized
+func (r Rational) common(o Rational) (Rational, Rational, error) {
+	l, err := scientific.LCM(r.Den(), o.Den())
+	if err != nil {
+		return Rational{}, Rational{}, err
+	}
+	a, err := multiply.Checked(r.num, l/r.Den())
+	if err != nil {
+		return Rational{}, Rational{}, err
+	}
+	b, err := multiply.Checked(o.num, l/o.Den())
+	if err != nil {
+		return Rational{}, Rational{}, err
+	}
+	return Rational{num: a, den: l}, Rational{num: b, den: l}, nil
+}
+
+// mul returns a/b * c/d, dividing a and d, and c and b by their common divisors first
+func mul(a, b, c, d int64) (Rational, error) {
+	g1, err := scientific.GCD(a, d)
+	if err != nil {
+		return Rational{}, err
+	}
+	g2, err := scientific.GCD(c, b)
+	if err != nil {
+		return Rational{}, err
+	}
+	if g1 != 0 {
+		a, d = a/g1, d/g1
+	}
+	if g2 != 0 {
+		c, b = c/g2, b/g2
+	}
+
+	num, err := multiply.Checked(a, c)
+	if err != nil {
+		return Rational{}, err
+	}
+	den, err := multiply.Checked(b, d)
+	if err != nil {
+		return Rational{}, err
+	}
+	return New(num, den)
+}
@@ -0,0 +1,137 @@
+package rational
+
+import (
+	"errors"
+	"math"
+	"testing"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+)
+
+// frac returns num/den as it is, the test writes it normalized
+func frac(num, den int64) Rational {
+	return Rational{num: num, den: den}
+}
+
+func TestNew(t *testing.T) {
+	for _, tt := range []struct {
+		num, den int64
+		want     Rational
+		err      error
+	}{
+		{2, 4, frac(1, 2), nil},
+		{2, -4, frac(-1, 2), nil},
+		{-2, -4, frac(1, 2), nil},
+		{6, 3, frac(2, 1), nil},
+		// 0 has a single form, 0/1, whatever the denominator
+		{0, 5, frac(0, 1), nil},
+		{0, -5, frac(0, 1), nil},
+		{math.MinInt64, 2, frac(math.MinInt64/2, 1), nil},
+		{1, 0, Rational{}, calculator.ErrDivisionByZero},
+		// The sign cannot move to the numerator, -MinInt64 does not fit into an int64
+		{math.MinInt64, -1, Rational{}, calculator.ErrOverflow},
+		{1, math.MinInt64, Rational{}, calculator.ErrOverflow},
+	} {
+		got, err := New(tt.num, tt.den)
+		if got != tt.want || !errors.Is(err, tt.err) {
+			t.Errorf("New(%d, %d) = %#v, %v, want %#v, %v", tt.num, tt.den, got, err, tt.want, tt.err)
+		}
+	}
+}
+
+func TestParse(t *testing.T) {
+	for _, tt := range []struct {
+		text string
+		want Rational
+	}{
+		{"3/4", frac(3, 4)},
+		{"-3/4", frac(-3, 4)},
+		{"2/-4", frac(-1, 2)},
+		{" 6 / 8 ", frac(3, 4)},
+		{"7", frac(7, 1)},
+		{"0.75", frac(3, 4)},
+		{"-.5", frac(-1, 2)},
+		{"0.10", frac(1, 10)},
+		{"-0", frac(0, 1)},
+	} {
+		got, err := Parse(tt.text)
+		if err != nil || got != tt.want {
+			t.Errorf("Parse(%q) = %#v, %v, want %#v", tt.text, got, err, tt.want)
+		}
+	}
+}
+
+func TestParseErrors(t *testing.T) {
+	for _, tt := range []struct {
+		text string
+		want error
+	}{
+		{"1/0", calculator.ErrDivisionByZero},
+		{"", calculator.ErrInvalidOperand},
+		{"abc", calculator.ErrInvalidOperand},
+		{"1.5/2", calculator.ErrInvalidOperand},
+		{"1/2/3", calculator.ErrInvalidOperand},
+		{"1.-5", calculator.ErrInvalidOperand},
+		{"1..5", calculator.ErrInvalidOperand},
+		{"99999999999999999999", calculator.ErrOverflow},
+		{"1/99999999999999999999", calculator.ErrOverflow},
+		{"0.99999999999999999999", calculator.ErrOverflow},
+		// 10^20 as a denominator does not fit into an int64
+		{"0.00000000000000000001", calculator.ErrOverflow},
+		{"-9223372036854775808/-1", calculator.ErrOverflow},
+	} {
+		if got, err := Parse(tt.text); !errors.Is(err, tt.want) {
+			t.Errorf("Parse(%q) = %v, %v, want %v", tt.text, got, err, tt.want)
+		}
+	}
+}
+
+func TestString(t *testing.T) {
+	for _, tt := range []struct {
+		r    Rational
+		want string
+	}{
+		{frac(-1, 2), "-1/2"},
+		{frac(3, 4), "3/4"},
+		{frac(7, 1), "7"},
+		{frac(0, 1), "0"},
+		// The zero value is 0
+		{Rational{}, "0"},
+	} {
+		if got := tt.r.String(); got != tt.want {
+			t.Errorf("%#v.String() = %q, want %q", tt.r, got, tt.want)
+		}
+	}
+}
+
+func TestArithmetic(t *testing.T) {
+	maxInt := FromInt(math.MaxInt64)
+	for _, tt := range []struct {
+		name string
+		got  func() (Rational, error)
+		want Rational
+		err  error
+	}{
+		{"add", func() (Rational, error) { return frac(1, 2).Add(frac(1, 3)) }, frac(5, 6), nil},
+		{"add to the zero value", func() (Rational, error) { return Rational{}.Add(frac(1, 3)) }, frac(1, 3), nil},
+		{"subtract", func() (Rational, error) { return frac(1, 2).Subtract(frac(1, 2)) }, frac(0, 1), nil},
+		{"multiply", func() (Rational, error) { return frac(2, 3).Multiply(frac(3, 4)) }, frac(1, 2), nil},
+		{"divide", func() (Rational, error) { return frac(1, 2).Divide(frac(1, 4)) }, frac(2, 1), nil},
+		{"divide by a negative", func() (Rational, error) { return frac(1, 2).Divide(frac(-2, 1)) }, frac(-1, 4), nil},
+		{"divide by zero", func() (Rational, error) { return frac(1, 2).Divide(Rational{}) }, Rational{}, calculator.ErrDivisionByZero},
+		{"add overflow", func() (Rational, error) { return maxInt.Add(FromInt(1)) }, Rational{}, calculator.ErrOverflow},
+		// The least common multiple of the denominators does not fit into an int64
+		{"add overflow of the denominator", func() (Rational, error) {
+			return frac(1, math.MaxInt64).Add(frac(1, math.MaxInt64-1))
+		}, Rational{}, calculator.ErrOverflow},
+		{"subtract overflow", func() (Rational, error) { return FromInt(math.MinInt64).Subtract(FromInt(1)) }, Rational{}, calculator.ErrOverflow},
+		{"multiply overflow", func() (Rational, error) { return maxInt.Multiply(FromInt(2)) }, Rational{}, calculator.ErrOverflow},
+		{"divide overflow", func() (Rational, error) { return maxInt.Divide(frac(1, 2)) }, Rational{}, calculator.ErrOverflow},
+		{"negate overflow", func() (Rational, error) { return FromInt(math.MinInt64).Negate() }, Rational{}, calculator.ErrOverflow},
+	} {
+		got, err := tt.got()
+		if got != tt.want || !errors.Is(err, tt.err) {
+			t.Errorf("%s = %v, %v, want %v, %v", tt.name, got, err, tt.want, tt.err)
+		}
+	}
+}
@@ -0,0 +1,227 @@
+package calculator
+
+import (
+	"errors"
+	"fmt"
+	"slices"
+	"sort"
+	"sync"
+)
+
+// Operations register themselves when their package is initialized, e.g the add package registers +
+// The expression parser, the evaluator and the help text look up operations here, so a new operation only needs
+// a package that registers it, see package builtin
+// This is the registry (or plugin) pattern, database/sql drivers are registered the same way
+
+// Precedence levels of operators, operators with a higher precedence are applied first
+// The levels are the ones of the Go specification, so 1 + 2 * 3 is 1 + (2 * 3) like in Go
+const (
+	PrecFunction       = 0 // Functions are called by name, e.g sqrt(2), and have no precedence
+	PrecAdditive       = 4 // + -
+	PrecMultiplicative = 5 // * /
+	PrecUnary          = 6 // -x
+)
+
+// ErrUnsupported is returned when an operation is applied to a type it does not work with, e.g sqrt to integers
+var ErrUnsupported = errors.New("not supported")
+
+// Info describes an operation
+type Info struct {
+	Name        string // Unique name, used to call functions, e.g add or sqrt
+	Symbol      string // Symbol of an operator, e.g +, empty for functions
+	Arity       int    // Number of operands
+	Precedence  int    // One of the Prec constants
+	Description string // One line shown in the help
+}
+
+// Operation is something the calculator can do, like adding two numbers or taking a square root
+// Integers are passed as int64 or uint64, floats as float64 and complex numbers as complex128,
+// the evaluator converts the result to the type it uses
+type Operation interface {
+	Info() Info
+	ApplyInt(overflow Overflow, args ...int64) (int64, error)
+	ApplyUint(overflow Overflow, args ...uint64) (uint64, error)
+	ApplyFloat(args ...float64) (float64, error)
+	ApplyComplex(args ...complex128) (complex128, error)
+}
+
+// Func is an Operation made of functions, an operation does not work with a type when its function is nil
+type Func struct {
+	Spec    Info
+	Int     func(overflow Overflow, args ...int64) (int64, error)
+	Uint    func(overflow Overflow, args ...uint64) (uint64, error)
+	Float   func(args ...float64) (float64, error)
+	Complex func(args ...complex128) (complex128, error)
+}
+
+func (f Func) Info() Info {
+	return f.Spec
+}
+
+func (f Func) ApplyInt(overflow Overflow, args ...int64) (int64, error) {
+	if err := f.check(f.Int == nil, "integers", len(args)); err != nil {
+		return 0, err
+	}
+	return f.Int(overflow, args...)
+}
+
+func (f Func) ApplyUint(overflow Overflow, args ...uint64) (uint64, error) {
+	if err := f.check(f.Uint == nil, "unsigned integers", len(args)); err != nil {
+		return 0, err
+	}
+	return f.Uint(overflow, args...)
+}
+
+func (f Func) ApplyFloat(args ...float64) (float64, error) {
+	if err := f.check(f.Float == nil, "floats", len(args)); err != nil {
+		return 0, err
+	}
+	return f.Float(args...)
+}
+
+func (f Func) ApplyComplex(args ...complex128) (complex128, error) {
+	if err := f.check(f.Complex == nil, "complex numbers", len(args)); err != nil {
+		return 0, err
+	}
+	return f.Complex(args...)
+}
+
+func (f Func) check(missing bool, types string, arity int) error {
+	if missing {
+		return fmt.Errorf("%w: %s does not work with %s", ErrUnsupported, f.Spec.Name, types)
+	}
+	if arity != f.Spec.Arity {
+		return fmt.Errorf("%s takes %d operands, not %d", f.Spec.Name, f.Spec.Arity, arity)
+	}
+	return nil
+}
+
+// Unary and Binary adapt functions like add.WithMode[int64] to the Int and Uint functions of Func
+func Unary[T any](f func(a T, overflow Overflow) (T, error)) func(Overflow, ...T) (T, error) {
+	return func(overflow Overflow, args ...T) (T, error) { return f(args[0], overflow) }
+}
+
+func Binary[T any](f func(a, b T, overflow Overflow) (T, error)) func(Overflow, ...T) (T, error) {
+	return func(overflow Overflow, args ...T) (T, error) { return f(args[0], args[1], overflow) }
+}
+
+// CheckedUnary and CheckedBinary adapt functions that always return ErrOverflow, whatever the overflow mode is
+func CheckedUnary[T any](f func(a T) (T, error)) func(Overflow, ...T) (T, error) {
+	return func(_ Overflow, args ...T) (T, error) { return f(args[0]) }
+}
+
+func CheckedBinary[T any](f func(a, b T) (T, error)) func(Overflow, ...T) (T, error) {
+	return func(_ Overflow, args ...T) (T, error) { return f(args[0], args[1]) }
+}
+
+// FloatUnary and FloatBinary adapt functions like add.CheckedFloat[float64] to the Float function of Func
+func FloatUnary(f func(x float64) (float64, error)) func(...float64) (float64, error) {
+	return func(args ...float64) (float64, error) { return f(args[0]) }
+}
+
+func FloatBinary(f func(x, y float64) (float64, error)) func(...float64) (float64, error) {
+	return func(args ...float64) (float64, error) { return f(args[0], args[1]) }
+}
+
+// ComplexUnary and ComplexBinary adapt functions like add.CheckedComplex[complex128] to the Complex function of Func
+func ComplexUnary(f func(x complex128) (complex128, error)) func(...complex128) (complex128, error) {
+	return func(args ...complex128) (complex128, error) { return f(args[0]) }
+}
+
+func ComplexBinary(f func(x, y complex128) (complex128, error)) func(...complex128) (complex128, error) {
+	return func(args ...complex128) (complex128, error) { return f(args[0], args[1]) }
+}
+
+var registry = struct {
+	sync.RWMutex
+	byName   map[string]Operation
+	bySymbol map[symbolKey]Operation
+	symbols  []string // Every symbol once, sorted by Register like Symbols returns them
+}{
+	byName:   map[string]Operation{},
+	bySymbol: map[symbolKey]Operation{},
+}
+
+// symbolKey tells apart operators that share a symbol, like - in 1 - 2 and -2
+type symbolKey struct {
+	symbol string
+	arity  int
+}
+
+// Register makes an operation available to the calculator
+// It panics when the name, or the symbol with the same arity, is already registered, like database/sql.Register does
+func Register(op Operation) {
+	info := op.Info()
+	registry.Lock()
+	defer registry.Unlock()
+
+	if _, ok := registry.byName[info.Name]; ok {
+		panic("calculator: Register called twice for operation " + info.Name)
+	}
+	key := symbolKey{symbol: info.Symbol, arity: info.Arity}
+	if info.Symbol != "" {
+		if _, ok := registry.bySymbol[key]; ok {
+			panic(fmt.Sprintf("calculator: Register called twice for operator %s with %d operands", info.Symbol, info.Arity))
+		}
+		registry.bySymbol[key] = op
+		if !slices.Contains(registry.symbols, info.Symbol) { // - is registered twice, for 1 - 2 and for -2
+			registry.symbols = append(registry.symbols, info.Symbol)
+			sortSymbols(registry.symbols)
+		}
+	}
+	registry.byName[info.Name] = op
+}
+
+// Lookup returns the operation with the given name
+func Lookup(name string) (Operation, bool) {
+	registry.RLock()
+	defer registry.RUnlock()
+	op, ok := registry.byName[name]
+	return op, ok
+}
+
+// LookupSymbol returns the operator with the given symbol and number of operands
+func LookupSymbol(symbol string, arity int) (Operation, bool) {
+	registry.RLock()
+	defer registry.RUnlock()
+	op, ok := registry.bySymbol[symbolKey{symbol: symbol, arity: arity}]
+	return op, ok
+}
+
+// Operations returns every registered operation
+// Operators come first, from the highest to the lowest precedence, followed by functions, sorted by name
+func Operations() []Operation {
+	registry.RLock()
+	ops := make([]Operation, 0, len(registry.byName))
+	for _, op := range registry.byName {
+		ops = append(ops, op)
+	}
+	registry.RUnlock()
+
+	sort.Slice(ops, func(i, j int) bool {
+		a, b := ops[i].Info(), ops[j].Info()
+		if a.Precedence != b.Precedence {
+			return a.Precedence > b.Precedence
+		}
+		return a.Name < b.Name
+	})
+	return ops
+}
+
+// Symbols returns the symbols of all operators, longest first, so that << is matched before <
+// The list is sorted once by Register and not on every call, the lexer asks for it for every expression
+func Symbols() []string {
+	registry.RLock()
+	defer registry.RUnlock()
+	return slices.Clone(registry.symbols)
+}
+
+// sortSymbols sorts the longest symbols first, symbols of the same length alphabetically
+func sortSymbols(symbols []string) {
+	sort.Slice(symbols, func(i, j int) bool {
+		if len(symbols[i]) != len(symbols[j]) {
+			return len(symbols[i]) > len(symbols[j])
+		}
+		return symbols[i] < symbols[j]
+	})
+}
@@ -0,0 +1,304 @@
+// Package repl implements an interactive calculator: a read-eval-print loop over the expr package
+package repl
+
+import (
+	"bufio"
+	"errors"
+	"fmt"
+	"io"
+	"strconv"
+	"strings"
+	"unicode"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+	"github.com/shashank-priyadarshi/training/calculator/expr"
+)
+
+const (
+	prompt    = "> "
+	rpnPrompt = "rpn> "
+)
+
+const help = `Type an expression and press enter to evaluate it, e.g (3 + 4) * 2 / 7
+Parentheses group operations, :ops lists the operators and the functions
+The mode decides the type of the numbers: 1 / 2 is 0 in int mode, and 0.5 in float mode
+Integers can be written in base 16, 8 and 2 like in Go: 0xff, 0o17 and 0b1010
+A number followed by i is imaginary like in Go, 4i, in every mode: 3 * i multiplies by a variable named i, 3i does not
+
+  let x = 3   bind the value of an expression to the variable x
+  ans         the result of the previous expression
+  !!          evaluate the previous line again
+  !n          evaluate line n of the history again
+
+Commands:
+  :help       show this help
+  :ops        show the operators and functions
+  :history    show the lines entered so far
+  :vars       show all variables
+  :mode       show the current mode and the available modes
+  :mode name  switch to another mode, variables are cleared
+              integer modes like int8 and uint8 stop on overflow, unless followed by :saturate or :wrap, e.g :mode uint8:wrap
+  :rpn        switch between infix and Reverse Polish Notation input
+  :base n     write integer results in base n, e.g :base 16 writes 255 as 0xff, :base 10 goes back
+  :quit       exit the calculator
+
+In RPN mode operators come after their operands, e.g 3 4 + 2 * is (3 + 4) * 2
+Every line prints the stack, the top of the stack is on the right
+  dup         push the top value again
+  swap        swap the two top values
+  drop        pop the top value
+  clear       remove every value
+`
+
+// REPL reads a line, evaluates it, prints the result and loops until the input ends or :quit is entered
+type REPL struct {
+	in       *bufio.Scanner
+	out      io.Writer
+	session  expr.Evaluator
+	history  []string // Lines that were evaluated, commands are not part of the history
+	rpn      bool     // Lines are read in Reverse Polish Notation instead of infix notation
+	base     int      // Base results are written in, kept when the mode changes
+	sessions Sessions // Creates the session of a mode, see SetSessions
+}
+
+// Sessions returns a new session for the mode, see SetSessions
+type Sessions func(mode string) (expr.Evaluator, error)
+
+// New returns a REPL reading lines from in and writing results to out
+// Its sessions come from expr.NewEvaluator, unless SetSessions is called
+func New(in io.Reader, out io.Writer) *REPL {
+	session, _ := expr.NewEvaluator(expr.DefaultMode)
+	return &REPL{
+		in:       bufio.NewScanner(in),
+		out:      out,
+		session:  session,
+		base:     10,
+		sessions: expr.NewEvaluator,
+	}
+}
+
+// Run loops until the input ends or :quit is entered
+func (r *REPL) Run() error {
+	fmt.Fprintln(r.out, "Calculator, type :help for help")
+
+	for {
+		fmt.Fprint(r.out, r.prompt())
+		if !r.in.Scan() {
+			fmt.Fprintln(r.out)
+			return r.in.Err()
+		}
+
+		raw := r.in.Text()
+		line := strings.TrimSpace(raw)
+		// shown is what the terminal shows before line, the caret of an error is placed after it
+		shown := r.prompt() + raw[:len(raw)-len(strings.TrimLeftFunc(raw, unicode.IsSpace))]
+		switch {
+		case line == "":
+			continue
+		case line == ":quit" || line == ":q":
+			return nil
+		case strings.HasPrefix(line, ":"):
+			r.command(line)
+			continue
+		case strings.HasPrefix(line, "!"):
+			recalled, err := r.recall(line)
+			if err != nil {
+				fmt.Fprintln(r.out, "error:", err)
+				continue
+			}
+			line, shown = recalled, "" // Printed on a line of its own, without the prompt
+			fmt.Fprintln(r.out, line)
+		}
+
+		r.history = append(r.history, line)
+		r.eval(line, shown)
+	}
+}
+
+// prompt returns the prompt of the current input notation
+func (r *REPL) prompt() string {
+	if r.rpn {
+		return rpnPrompt
+	}
+	return prompt
+}
+
+// eval evaluates the line, shown is the text before it on the screen, see printError
+func (r *REPL) eval(line, shown string) {
+	if r.rpn {
+		r.evalRPN(line, shown)
+		return
+	}
+
+	stmt, v, err := r.session.Eval(line)
+	if err != nil {
+		r.printError(err, line, shown)
+		return
+	}
+	if stmt.Name != "" {
+		fmt.Fprintf(r.out, "%s = %s\n", stmt.Name, v)
+		return
+	}
+	fmt.Fprintln(r.out, v)
+}
+
+// evalRPN evaluates an RPN line and prints the stack
+func (r *REPL) evalRPN(line, shown string) {
+	stack, err := r.session.RPN(line)
+	if err != nil {
+		r.printError(err, line, shown)
+	}
+	fmt.Fprintln(r.out, "stack: ["+strings.Join(stack, " ")+"]")
+}
+
+// printError points at the column of the error, below the line, which is shown after the text shown
+func (r *REPL) printError(err error, line, shown string) {
+	col := 0
+	var syntaxErr *expr.SyntaxError
+	var evalErr *expr.EvalError
+	switch {
+	case errors.As(err, &syntaxErr):
+		col = syntaxErr.Col
+	case errors.As(err, &evalErr):
+		col = evalErr.Col
+	}
+	if col > 0 {
+		fmt.Fprintln(r.out, caret(shown, line, col))
+	}
+	fmt.Fprintln(r.out, "error:", err)
+}
+
+// caret returns a ^ below column col of line, which is shown after the text shown
+// Columns count runes and not bytes: the π of π + x takes two bytes, but the caret must move by one space for it
+// A tab stays a tab, so the terminal moves the caret as far as it moved the text, and a combining accent takes no space
+func caret(shown, line string, col int) string {
+	runes := []rune(line)
+	col = min(col, len(runes)+1) // The column after the last rune is the end of the line
+
+	var b strings.Builder
+	for _, r := range shown + string(runes[:col-1]) {
+		switch {
+		case r == '\t':
+			b.WriteRune('\t')
+		case unicode.Is(unicode.Mn, r):
+		default:
+			b.WriteRune(' ')
+		}
+	}
+	return b.String() + "^"
+}
+
+// recall returns the line of the history referred to by !! or !n, where n counts from 1
+func (r *REPL) recall(line string) (string, error) {
+	if len(r.history) == 0 {
+		return "", errors.New("history is empty")
+	}
+	if line == "!!" {
+		return r.history[len(r.history)-1], nil
+	}
+
+	n, err := strconv.Atoi(line[1:])
+	if err != nil || n < 1 || n > len(r.history) {
+		return "", fmt.Errorf("%s: expected !! or !n with n between 1 and %d", line, len(r.history))
+	}
+	return r.history[n-1], nil
+}
+
+func (r *REPL) command(line string) {
+	if mode, ok := strings.CutPrefix(line, ":mode "); ok {
+		r.switchMode(strings.TrimSpace(mode))
+		return
+	}
+	if base, ok := strings.CutPrefix(line, ":base "); ok {
+		r.setBase(strings.TrimSpace(base))
+		return
+	}
+
+	switch line {
+	case ":help", ":h":
+		fmt.Fprint(r.out, help)
+	case ":ops":
+		r.operations()
+	case ":history":
+		for i, entry := range r.history {
+			fmt.Fprintf(r.out, "%4d  %s\n", i+1, entry)
+		}
+	case ":vars":
+		for _, name := range r.session.Vars() {
+			v, _ := r.session.Lookup(name)
+			fmt.Fprintf(r.out, "%s = %s\n", name, v)
+		}
+	case ":rpn":
+		r.rpn = !r.rpn
+		if r.rpn {
+			fmt.Fprintln(r.out, "RPN mode, stack: ["+strings.Join(r.session.Stack(), " ")+"]")
+			return
+		}
+		fmt.Fprintln(r.out, "infix mode")
+	case ":mode":
+		fmt.Fprintf(r.out, "mode: %s, available modes: %s\n", r.session.Mode(), strings.Join(expr.Modes(), ", "))
+	case ":base":
+		fmt.Fprintln(r.out, "base:", r.base)
+	default:
+		fmt.Fprintf(r.out, "unknown command %s, type :help for help\n", line)
+	}
+}
+
+func (r *REPL) switchMode(mode string) {
+	base := r.base
+	if err := r.SetMode(mode); err != nil {
+		fmt.Fprintln(r.out, "error:", err)
+		return
+	}
+	fmt.Fprintln(r.out, "mode:", mode)
+	if r.base != base {
+		fmt.Fprintf(r.out, "base: %d, %s mode cannot write numbers in base %d\n", r.base, mode, base)
+	}
+}
+
+func (r *REPL) setBase(text string) {
+	base, err := strconv.Atoi(text)
+	if err == nil {
+		err = r.session.SetBase(base)
+	}
+	if err != nil {
+		fmt.Fprintln(r.out, "error:", err)
+		return
+	}
+	r.base = base
+	fmt.Fprintln(r.out, "base:", base)
+}
+
+// SetSessions makes the REPL get its sessions from sessions, e.g from the app.Calculator port, which records
+// what they evaluate
+// The current session is replaced by a new one of the same mode, variables are cleared
+func (r *REPL) SetSessions(sessions Sessions) error {
+	r.sessions = sessions
+	return r.SetMode(r.session.Mode())
+}
+
+// SetMode switches to another mode, see expr.Modes, variables are cleared
+// The base is kept when the new mode can write numbers in it, otherwise it goes back to 10
+func (r *REPL) SetMode(mode string) error {
+	session, err := r.sessions(mode)
+	if err != nil {
+		return err
+	}
+	if session.SetBase(r.base) != nil {
+		r.base = 10
+	}
+	r.session = session
+	return nil
+}
+
+// operations lists every operation of the calculator registry, so that new operations show up without changing the REPL
+func (r *REPL) operations() {
+	for _, op := range calculator.Operations() {
+		info := op.Info()
+		kind := "function"
+		if info.Symbol != "" {
+			kind = fmt.Sprintf("operator %s", info.Symbol)
+		}
+		fmt.Fprintf(r.out, "  %-10s %-12s %s\n", info.Name, kind, info.Description)
+	}
+}
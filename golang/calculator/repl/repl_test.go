@@ -0,0 +1,42 @@
+package repl
+
+import (
+	"strings"
+	"testing"
+)
+
+func TestCaret(t *testing.T) {
+	for _, tt := range []struct {
+		shown, line string
+		col         int
+		want        string
+	}{
+		{"> ", "1 + x", 5, strings.Repeat(" ", 6) + "^"},
+		{"rpn> ", "1 x +", 3, strings.Repeat(" ", 7) + "^"},
+		// A line recalled with !n is printed without the prompt
+		{"", "1 + x", 5, strings.Repeat(" ", 4) + "^"},
+		// π is two bytes but one column, and the combining accent of e\u0301 takes no column at all
+		{"> ", "π + x", 5, strings.Repeat(" ", 6) + "^"},
+		{"> ", "e\u0301 + x", 6, strings.Repeat(" ", 6) + "^"},
+		{">\t", "1 + x", 5, " \t" + strings.Repeat(" ", 4) + "^"},
+		// A column past the end points after the last rune
+		{"> ", "1 +", 9, strings.Repeat(" ", 5) + "^"},
+	} {
+		if got := caret(tt.shown, tt.line, tt.col); got != tt.want {
+			t.Errorf("caret(%q, %q, %d) = %q, want %q", tt.shown, tt.line, tt.col, got, tt.want)
+		}
+	}
+}
+
+func TestCaretBelowTheError(t *testing.T) {
+	var out strings.Builder
+	r := New(strings.NewReader("  1 + $\n"), &out)
+	if err := r.Run(); err != nil {
+		t.Fatal(err)
+	}
+	// The terminal echoes the typed line, the output only has the prompt, so the caret line follows it
+	// The caret moves past the prompt, the two leading spaces and 1 + to the $ in column 5
+	if want := "> " + strings.Repeat(" ", len("> ")+len("  1 + ")) + "^\n"; !strings.Contains(out.String(), want) {
+		t.Errorf("output %q does not contain %q", out.String(), want)
+	}
+}
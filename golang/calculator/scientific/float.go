@@ -0,0 +1,142 @@
+package scientific
+
+import (
+	"math"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+)
+
+// Pow returns x raised to the power y: Pow(2, 0.5) is the square root of 2
+func Pow(x, y float64) (float64, error) {
+	if err := finite("pow", x, y); err != nil {
+		return 0, err
+	}
+	if x == 0 && y < 0 {
+		return 0, calculator.NewError("pow", calculator.ErrDivisionByZero, x, y)
+	}
+	// A fractional power of a negative number is not a real number, e.g (-8)^0.5
+	if x < 0 && y != math.Trunc(y) {
+		return 0, calculator.NewError("pow", calculator.ErrInvalidOperand, x, y)
+	}
+	return calculator.CheckFloat("pow", math.Pow(x, y), x, y)
+}
+
+// Mod returns x modulo y, which has the sign of y like ModInt: Mod(-7.5, 2) is 0.5
+func Mod(x, y float64) (float64, error) {
+	if err := finite("mod", x, y); err != nil {
+		return 0, err
+	}
+	if y == 0 {
+		return 0, calculator.NewError("mod", calculator.ErrDivisionByZero, x, y)
+	}
+	r := math.Mod(x, y)
+	if r != 0 && (r < 0) != (y < 0) {
+		r += y
+	}
+	return r, nil
+}
+
+// Sqrt returns the square root of x, x cannot be negative
+func Sqrt(x float64) (float64, error) {
+	if err := finite("sqrt", x); err != nil {
+		return 0, err
+	}
+	if x < 0 {
+		return 0, calculator.NewError("sqrt", calculator.ErrInvalidOperand, x)
+	}
+	return math.Sqrt(x), nil
+}
+
+// Trigonometric functions take and return angles in radians, math.Pi radians is 180 degrees
+
+func Sin(x float64) (float64, error) {
+	if err := finite("sin", x); err != nil {
+		return 0, err
+	}
+	return math.Sin(x), nil
+}
+
+func Cos(x float64) (float64, error) {
+	if err := finite("cos", x); err != nil {
+		return 0, err
+	}
+	return math.Cos(x), nil
+}
+
+func Tan(x float64) (float64, error) {
+	if err := finite("tan", x); err != nil {
+		return 0, err
+	}
+	return math.Tan(x), nil
+}
+
+// Asin is the inverse of Sin, x has to be between -1 and 1
+func Asin(x float64) (float64, error) {
+	if err := finite("asin", x); err != nil {
+		return 0, err
+	}
+	if x < -1 || x > 1 {
+		return 0, calculator.NewError("asin", calculator.ErrInvalidOperand, x)
+	}
+	return math.Asin(x), nil
+}
+
+// Acos is the inverse of Cos, x has to be between -1 and 1
+func Acos(x float64) (float64, error) {
+	if err := finite("acos", x); err != nil {
+		return 0, err
+	}
+	if x < -1 || x > 1 {
+		return 0, calculator.NewError("acos", calculator.ErrInvalidOperand, x)
+	}
+	return math.Acos(x), nil
+}
+
+// Atan is the inverse of Tan
+func Atan(x float64) (float64, error) {
+	if err := finite("atan", x); err != nil {
+		return 0, err
+	}
+	return math.Atan(x), nil
+}
+
+// Logarithms are only defined for positive numbers, the logarithm of 0 would be -Inf
+
+// Ln returns the natural logarithm of x, with base e
+func Ln(x float64) (float64, error) {
+	return logarithm("ln", math.Log, x)
+}
+
+// Log10 returns the logarithm of x with base 10: Log10(1000) is 3
+func Log10(x float64) (float64, error) {
+	return logarithm("log10", math.Log10, x)
+}
+
+// Log2 returns the logarithm of x with base 2: Log2(1024) is 10
+func Log2(x float64) (float64, error) {
+	return logarithm("log2", math.Log2, x)
+}
+
+func logarithm(op string, log func(float64) float64, x float64) (float64, error) {
+	if err := finite(op, x); err != nil {
+		return 0, err
+	}
+	if x <= 0 {
+		return 0, calculator.NewError(op, calculator.ErrInvalidOperand, x)
+	}
+	return log(x), nil
+}
+
+// finite returns calculator.ErrInvalidOperand when one of the operands is NaN or infinite
+func finite(op string, operands ...float64) error {
+	for _, x := range operands {
+		if math.IsNaN(x) || math.IsInf(x, 0) {
+			args := make([]any, len(operands))
+			for i, operand := range operands {
+				args[i] = operand
+			}
+			return calculator.NewError(op, calculator.ErrInvalidOperand, args...)
+		}
+	}
+	return nil
+}
@@ -0,0 +1,125 @@
+// Package scientific has the functions of a scientific calculator: powers, roots, factorials, trigonometry and logarithms
+// Every function checks its operands first, e.g the square root of a negative number or the logarithm of 0
+// return calculator.ErrInvalidOperand instead of NaN or -Inf
+package scientific
+
+import (
+	"math"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+	"github.com/shashank-priyadarshi/training/calculator/divide"
+	"github.com/shashank-priyadarshi/training/calculator/multiply"
+)
+
+// PowInt returns base raised to the power exp: PowInt(2, 10) is 1024
+// exp cannot be negative, because the result would be a fraction
+func PowInt[T calculator.Integer](base, exp T) (T, error) {
+	if exp < 0 {
+		return 0, calculator.NewError("pow", calculator.ErrInvalidOperand, base, exp)
+	}
+
+	// Exponentiation by squaring: 2^10 = (2^2)^5 = 4 * (4^2)^2, which needs 4 multiplications instead of 9
+	result, b, e := T(1), base, exp
+	for e > 0 {
+		var err error
+		if e&1 == 1 {
+			if result, err = multiply.Checked(result, b); err != nil {
+				return 0, calculator.NewError("pow", calculator.ErrOverflow, base, exp)
+			}
+		}
+		e >>= 1
+		if e > 0 {
+			if b, err = multiply.Checked(b, b); err != nil {
+				return 0, calculator.NewError("pow", calculator.ErrOverflow, base, exp)
+			}
+		}
+	}
+	return result, nil
+}
+
+// ModInt returns a modulo b, which has the sign of b: ModInt(-7, 3) is 2
+// divide.Remainder has the sign of a instead: divide.Remainder(-7, 3) is -1
+func ModInt[T calculator.Integer](a, b T) (T, error) {
+	r, err := divide.Remainder(a, b)
+	if err != nil {
+		return 0, calculator.NewError("mod", calculator.ErrDivisionByZero, a, b)
+	}
+	if r != 0 && (r < 0) != (b < 0) {
+		r += b
+	}
+	return r, nil
+}
+
+// ISqrt returns the largest integer whose square is not bigger than n: ISqrt(17) is 4
+func ISqrt[T calculator.Integer](n T) (T, error) {
+	if n < 0 {
+		return 0, calculator.NewError("isqrt", calculator.ErrInvalidOperand, n)
+	}
+	if n < 2 {
+		return n, nil
+	}
+
+	// math.Sqrt gets close, but a float64 has only 53 bits so it can be off by a little for large numbers
+	// x > n/x is the same as x*x > n, without the risk of x*x overflowing
+	x := T(math.Sqrt(float64(n)))
+	for x > n/x {
+		x--
+	}
+	for x+1 <= n/(x+1) {
+		x++
+	}
+	return x, nil
+}
+
+// Factorial returns 1 * 2 * ... * n: Factorial(5) is 120
+// It grows fast, Factorial(21) does not fit into an int64 anymore
+func Factorial[T calculator.Integer](n T) (T, error) {
+	if n < 0 {
+		return 0, calculator.NewError("fact", calculator.ErrInvalidOperand, n)
+	}
+
+	result := T(1)
+	for i := T(2); i <= n; i++ {
+		var err error
+		if result, err = multiply.Checked(result, i); err != nil {
+			return 0, calculator.NewError("fact", calculator.ErrOverflow, n)
+		}
+	}
+	return result, nil
+}
+
+// GCD returns the greatest common divisor of a and b, which is never negative: GCD(12, -18) is 6
+func GCD[T calculator.Integer](a, b T) (T, error) {
+	x, y := a, b
+	for y != 0 {
+		x, y = y, x%y
+	}
+	if x < 0 {
+		// The smallest signed value has no positive counterpart, GCD[int8](-128, 0) would be 128
+		lo, _ := calculator.Limits[T]()
+		if x == lo {
+			return 0, calculator.NewError("gcd", calculator.ErrOverflow, a, b)
+		}
+		x = -x
+	}
+	return x, nil
+}
+
+// LCM returns the least common multiple of a and b, which is never negative: LCM(4, 6) is 12
+func LCM[T calculator.Integer](a, b T) (T, error) {
+	if a == 0 || b == 0 {
+		return 0, nil
+	}
+	g, err := GCD(a, b)
+	if err != nil {
+		return 0, calculator.NewError("lcm", calculator.ErrOverflow, a, b)
+	}
+	l, err := multiply.Checked(a/g, b)
+	if err == nil && l < 0 {
+		l, err = multiply.Checked(l, ^T(0)) // All bits set is -1 for signed types
+	}
+	if err != nil {
+		return 0, calculator.NewError("lcm", calculator.ErrOverflow, a, b)
+	}
+	return l, nil
+}
@@ -0,0 +1,75 @@
+package scientific
+
+import (
+	"fmt"
+	"math"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+)
+
+func init() {
+	register(2, "pow", "pow(x, y) raises x to the power y",
+		calculator.CheckedBinary(PowInt[int64]), calculator.CheckedBinary(PowInt[uint64]), calculator.FloatBinary(Pow))
+	register(2, "mod", "mod(a, b) is a modulo b, it has the sign of b",
+		calculator.CheckedBinary(ModInt[int64]), calculator.CheckedBinary(ModInt[uint64]), calculator.FloatBinary(Mod))
+
+	// Integer functions, floats can be passed to them as long as they are whole numbers
+	isqrt, fact := calculator.CheckedUnary(ISqrt[int64]), calculator.CheckedUnary(Factorial[int64])
+	gcd, lcm := calculator.CheckedBinary(GCD[int64]), calculator.CheckedBinary(LCM[int64])
+	register(1, "isqrt", "isqrt(n) is the largest integer whose square is not bigger than n",
+		isqrt, calculator.CheckedUnary(ISqrt[uint64]), whole(isqrt))
+	register(1, "fact", "fact(n) is 1 * 2 * ... * n",
+		fact, calculator.CheckedUnary(Factorial[uint64]), whole(fact))
+	register(2, "gcd", "gcd(a, b) is the greatest common divisor of a and b",
+		gcd, calculator.CheckedBinary(GCD[uint64]), whole(gcd))
+	register(2, "lcm", "lcm(a, b) is the least common multiple of a and b",
+		lcm, calculator.CheckedBinary(LCM[uint64]), whole(lcm))
+
+	// Float functions
+	register(1, "sqrt", "sqrt(x) is the square root of x", nil, nil, calculator.FloatUnary(Sqrt))
+	register(1, "sin", "sin(x) is the sine of x radians", nil, nil, calculator.FloatUnary(Sin))
+	register(1, "cos", "cos(x) is the cosine of x radians", nil, nil, calculator.FloatUnary(Cos))
+	register(1, "tan", "tan(x) is the tangent of x radians", nil, nil, calculator.FloatUnary(Tan))
+	register(1, "asin", "asin(x) is the angle in radians whose sine is x", nil, nil, calculator.FloatUnary(Asin))
+	register(1, "acos", "acos(x) is the angle in radians whose cosine is x", nil, nil, calculator.FloatUnary(Acos))
+	register(1, "atan", "atan(x) is the angle in radians whose tangent is x", nil, nil, calculator.FloatUnary(Atan))
+	register(1, "ln", "ln(x) is the logarithm of x with base e", nil, nil, calculator.FloatUnary(Ln))
+	register(1, "log10", "log10(x) is the logarithm of x with base 10", nil, nil, calculator.FloatUnary(Log10))
+	register(1, "log2", "log2(x) is the logarithm of x with base 2", nil, nil, calculator.FloatUnary(Log2))
+}
+
+func register(
+	arity int,
+	name, description string,
+	i func(calculator.Overflow, ...int64) (int64, error),
+	u func(calculator.Overflow, ...uint64) (uint64, error),
+	f func(...float64) (float64, error),
+) {
+	calculator.Register(calculator.Func{
+		Spec: calculator.Info{
+			Name:        name,
+			Arity:       arity,
+			Precedence:  calculator.PrecFunction,
+			Description: description,
+		},
+		Int:   i,
+		Uint:  u,
+		Float: f,
+	})
+}
+
+// whole lets floats be passed to an integer function, fact(5.0) works but fact(5.5) does not
+func whole(f func(calculator.Overflow, ...int64) (int64, error)) func(...float64) (float64, error) {
+	return func(args ...float64) (float64, error) {
+		ints := make([]int64, len(args))
+		for i, x := range args {
+			// Beyond 2^53 a float64 cannot hold every whole number anymore
+			if x != math.Trunc(x) || math.Abs(x) > 1<<53 {
+				return 0, fmt.Errorf("%w: %v is not a whole number", calculator.ErrInvalidOperand, x)
+			}
+			ints[i] = int64(x)
+		}
+		v, err := f(calculator.OverflowError, ints...)
+		return float64(v), err
+	}
+}
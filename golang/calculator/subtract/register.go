@@ -0,0 +1,46 @@
+package subtract
+
+import "github.com/shashank-priyadarshi/training/calculator"
+
+func init() {
+	calculator.Register(calculator.Func{
+		Spec: calculator.Info{
+			Name:        "subtract",
+			Symbol:      "-",
+			Arity:       2,
+			Precedence:  calculator.PrecAdditive,
+			Description: "a - b subtracts b from a",
+		},
+		Int:     calculator.Binary(WithMode[int64]),
+		Uint:    calculator.Binary(WithMode[uint64]),
+		Float:   calculator.FloatBinary(CheckedFloat[float64]),
+		Complex: calculator.ComplexBinary(CheckedComplex[complex128]),
+	})
+
+	// Negating is subtracting from 0, which also catches values that have no negative, like -128 for int8
+	calculator.Register(calculator.Func{
+		Spec: calculator.Info{
+			Name:        "negate",
+			Symbol:      "-",
+			Arity:       1,
+			Precedence:  calculator.PrecUnary,
+			Description: "-a changes the sign of a",
+		},
+		Int:     calculator.Unary(func(a int64, overflow calculator.Overflow) (int64, error) { return WithMode(0, a, overflow) }),
+		Uint:    calculator.Unary(func(a uint64, overflow calculator.Overflow) (uint64, error) { return WithMode(0, a, overflow) }),
+		Float:   func(args ...float64) (float64, error) { return -args[0], nil },
+		Complex: func(args ...complex128) (complex128, error) { return -args[0], nil },
+	})
+
+	calculator.Register(calculator.Func{
+		Spec: calculator.Info{
+			Name:        "absdiff",
+			Arity:       2,
+			Precedence:  calculator.PrecFunction,
+			Description: "absdiff(a, b) is how far apart a and b are, it is never negative",
+		},
+		Int:   calculator.CheckedBinary(func(a, b int64) (int64, error) { return AbsDiff(a, b), nil }),
+		Uint:  calculator.CheckedBinary(func(a, b uint64) (uint64, error) { return AbsDiff(a, b), nil }),
+		Float: calculator.FloatBinary(func(a, b float64) (float64, error) { return AbsDiff(a, b), nil }),
+	})
+}
@@ -1,8 +1,70 @@
 package subtract
 
-func Subtract(a, b int) int {
+import "github.com/shashank-priyadarshi/training/calculator"
+
+// Subtract returns a - b for every integer and floating-point type, Subtract(1, 2) is -1
+// Until now Subtract returned 1 for Subtract(1, 2), use AbsDiff for that
+func Subtract[T calculator.Number](a, b T) T {
+	return a - b
+}
+
+// AbsDiff returns how far apart a and b are, which is never negative: AbsDiff(1, 2) and AbsDiff(2, 1) are both 1
+func AbsDiff[T calculator.Number](a, b T) T {
 	if a < b {
 		return b - a
 	}
 	return a - b
 }
+
+// Checked returns a - b, but returns calculator.ErrOverflow instead of silently wrapping around
+// It works for every integer type: Checked[int8](100, -100) fails because 200 does not fit into an int8,
+// and Checked[uint8](1, 2) fails because a uint8 cannot be negative
+func Checked[T calculator.Integer](a, b T) (T, error) {
+	c := a - b
+	// Subtracting a positive number can only make the result smaller, and subtracting a negative number can only make it bigger
+	// If that is not the case, the result has wrapped around
+	if (b > 0 && c > a) || (b < 0 && c < a) {
+		return 0, calculator.NewError("subtract", calculator.ErrOverflow, a, b)
+	}
+	return c, nil
+}
+
+// Saturating returns a - b, stopping at the largest or the smallest value of T: Saturating[int8](100, -100) is 127,
+// and Saturating[uint8](1, 2) is 0
+func Saturating[T calculator.Integer](a, b T) T {
+	c, err := Checked(a, b)
+	if err == nil {
+		return c
+	}
+	lo, hi := calculator.Limits[T]()
+	if b > 0 {
+		return lo
+	}
+	return hi
+}
+
+// Wrapping returns a - b, wrapping around like the - operator does: Wrapping[int8](100, -100) is -56
+func Wrapping[T calculator.Integer](a, b T) T {
+	return a - b
+}
+
+// WithMode returns a - b, overflow decides between Checked, Saturating and Wrapping
+func WithMode[T calculator.Integer](a, b T, overflow calculator.Overflow) (T, error) {
+	switch overflow {
+	case calculator.OverflowSaturate:
+		return Saturating(a, b), nil
+	case calculator.OverflowWrap:
+		return Wrapping(a, b), nil
+	}
+	return Checked(a, b)
+}
+
+// CheckedFloat returns a - b, returning an error for NaN or infinite operands, or when the result becomes infinite
+func CheckedFloat[T calculator.Float](a, b T) (T, error) {
+	return calculator.CheckFloat("subtract", a-b, a, b)
+}
+
+// CheckedComplex subtracts complex numbers like CheckedFloat, the real and the imaginary parts are checked
+func CheckedComplex[T calculator.Complex](a, b T) (T, error) {
+	return calculator.CheckComplex("subtract", a-b, a, b)
+}
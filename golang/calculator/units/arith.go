@@ -0,0 +1,78 @@
+package units
+
+import (
+	"errors"
+	"fmt"
+	"strconv"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+)
+
+// Arith evaluates expressions with Quantity, selected with the units mode of the expr package
+// A number followed by a unit is a quantity, e.g 5 km + 300 m is 5.3 km, and 2 GiB / 512 MiB is 4
+type Arith struct{}
+
+func (Arith) Name() string {
+	return "units"
+}
+
+// Literal reads a number without a unit, like 1.5 or 0xff
+func (Arith) Literal(text string) (Quantity, error) {
+	v, err := calculator.ParseFloat(text)
+	if errors.Is(err, strconv.ErrRange) {
+		return Quantity{}, fmt.Errorf("%w: %s does not fit into a float64", calculator.ErrOverflow, text)
+	}
+	if err != nil {
+		return Quantity{}, fmt.Errorf("%w: %s is not a number", calculator.ErrInvalidOperand, text)
+	}
+	return Number(v), nil
+}
+
+// UnitLiteral reads a number followed by a unit, e.g 5 km
+func (a Arith) UnitLiteral(text, unit string) (Quantity, error) {
+	q, err := a.Literal(text)
+	if err != nil {
+		return Quantity{}, err
+	}
+	return New(q.Value, unit)
+}
+
+// Apply applies the operators to quantities, and the functions of the calculator registry to numbers without a unit,
+// sqrt(16) works but sqrt(16 m) does not
+func (Arith) Apply(op string, args ...Quantity) (Quantity, error) {
+	switch {
+	case op == "plus" && len(args) == 1:
+		return args[0], nil
+	case op == "negate" && len(args) == 1:
+		return args[0].Negate(), nil
+	case op == "add" && len(args) == 2:
+		return args[0].Add(args[1])
+	case op == "subtract" && len(args) == 2:
+		return args[0].Subtract(args[1])
+	case op == "multiply" && len(args) == 2:
+		return args[0].Multiply(args[1])
+	case op == "divide" && len(args) == 2:
+		return args[0].Divide(args[1])
+	}
+
+	f, ok := calculator.Lookup(op)
+	if !ok {
+		return Quantity{}, fmt.Errorf("%s is not available in units mode", op)
+	}
+	floats := make([]float64, len(args))
+	for i, arg := range args {
+		if arg.Dim != (Dimension{}) {
+			return Quantity{}, fmt.Errorf("%w: %s only works with numbers without a unit, not %v", ErrDimension, op, arg)
+		}
+		floats[i] = arg.Value
+	}
+	v, err := f.ApplyFloat(floats...)
+	if err != nil {
+		return Quantity{}, err
+	}
+	return Number(v), nil
+}
+
+func (Arith) Format(v Quantity) string {
+	return v.String()
+}
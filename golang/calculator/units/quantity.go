@@ -0,0 +1,252 @@
+package units
+
+import (
+	"errors"
+	"fmt"
+	"sort"
+	"strconv"
+	"strings"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+	"github.com/shashank-priyadarshi/training/calculator/add"
+	"github.com/shashank-priyadarshi/training/calculator/divide"
+	"github.com/shashank-priyadarshi/training/calculator/multiply"
+	"github.com/shashank-priyadarshi/training/calculator/subtract"
+)
+
+var (
+	ErrDimension   = errors.New("dimensions do not match") // e.g adding meters to seconds
+	ErrUnknownUnit = errors.New("unknown unit")
+)
+
+// Base dimensions, a Dimension counts how many times each of them is multiplied in
+const (
+	Length = iota
+	Time
+	Mass
+	Data
+	numDimensions
+)
+
+// Dimension holds the exponent of every base dimension: a speed is Length 1 and Time -1, meters per second
+// Quantities can only be added or subtracted when their dimensions are equal
+type Dimension [numDimensions]int8
+
+// baseUnits are the names of the base units, in the order of the base dimensions
+var baseUnits = [numDimensions]string{"m", "s", "kg", "B"}
+
+// Unit is a unit that can be written after a number, e.g km
+type Unit struct {
+	Name   string
+	Factor float64 // Number of base units in one unit, e.g 1000 for km
+	Dim    Dimension
+}
+
+// unitTable holds the units that can be written after a number, see Units
+var unitTable = map[string]Unit{}
+
+func init() {
+	for _, u := range []Unit{
+		{"mm", float64(Millimeter), Dimension{Length: 1}},
+		{"cm", float64(Centimeter), Dimension{Length: 1}},
+		{"m", float64(Meter), Dimension{Length: 1}},
+		{"km", float64(Kilometer), Dimension{Length: 1}},
+		{"ms", float64(Millisecond), Dimension{Time: 1}},
+		{"s", float64(Second), Dimension{Time: 1}},
+		{"min", float64(Minute), Dimension{Time: 1}},
+		{"h", float64(Hour), Dimension{Time: 1}},
+		{"g", float64(Gram), Dimension{Mass: 1}},
+		{"kg", float64(Kilogram), Dimension{Mass: 1}},
+		{"B", float64(Byte), Dimension{Data: 1}},
+		{"KiB", float64(KiB), Dimension{Data: 1}},
+		{"MiB", float64(MiB), Dimension{Data: 1}},
+		{"GiB", float64(GiB), Dimension{Data: 1}},
+		{"KB", float64(KB), Dimension{Data: 1}},
+		{"MB", float64(MB), Dimension{Data: 1}},
+		{"GB", float64(GB), Dimension{Data: 1}},
+	} {
+		unitTable[u.Name] = u
+	}
+	unitTable["byte"], unitTable["bytes"] = unitTable["B"], unitTable["B"]
+}
+
+// Units returns the names of the units, sorted by name
+func Units() []string {
+	names := make([]string, 0, len(unitTable))
+	for name := range unitTable {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return names
+}
+
+// Quantity is a value with a dimension, e.g 5 km
+// Value is always in base units, 5 km is stored as 5000 with the dimension of a length
+// Unit is only used to print the quantity, it is the unit the quantity was written with
+type Quantity struct {
+	Value float64
+	Dim   Dimension
+	Unit  string // Empty for numbers without a unit, and for results printed in base units, e.g m/s
+}
+
+// New returns value written in the unit, e.g New(5, "km")
+func New(value float64, unit string) (Quantity, error) {
+	u, ok := unitTable[unit]
+	if !ok {
+		return Quantity{}, fmt.Errorf("%w %q, available units are %s", ErrUnknownUnit, unit, strings.Join(Units(), ", "))
+	}
+	v, err := multiply.CheckedFloat(value, u.Factor)
+	if err != nil {
+		return Quantity{}, err
+	}
+	return Quantity{Value: v, Dim: u.Dim, Unit: unit}, nil
+}
+
+// Number returns a quantity without a unit, like 2 in 2 * 5 km
+func Number(value float64) Quantity {
+	return Quantity{Value: value}
+}
+
+// Add returns q + o, both must have the same dimension: 5 km + 300 m is 5.3 km, 5 km + 3 s fails with ErrDimension
+// The result is printed in the unit of q
+func (q Quantity) Add(o Quantity) (Quantity, error) {
+	if q.Dim != o.Dim {
+		return Quantity{}, calculator.NewError("add", ErrDimension, q, o)
+	}
+	v, err := add.CheckedFloat(q.Value, o.Value)
+	if err != nil {
+		return Quantity{}, err
+	}
+	return Quantity{Value: v, Dim: q.Dim, Unit: firstUnit(q, o)}, nil
+}
+
+// Subtract returns q - o, both must have the same dimension
+func (q Quantity) Subtract(o Quantity) (Quantity, error) {
+	if q.Dim != o.Dim {
+		return Quantity{}, calculator.NewError("subtract", ErrDimension, q, o)
+	}
+	v, err := subtract.CheckedFloat(q.Value, o.Value)
+	if err != nil {
+		return Quantity{}, err
+	}
+	return Quantity{Value: v, Dim: q.Dim, Unit: firstUnit(q, o)}, nil
+}
+
+// Multiply returns q * o, the exponents of the dimensions add up: m * m is m^2
+func (q Quantity) Multiply(o Quantity) (Quantity, error) {
+	v, err := multiply.CheckedFloat(q.Value, o.Value)
+	if err != nil {
+		return Quantity{}, err
+	}
+	dim, err := q.Dim.plus(o.Dim, 1)
+	if err != nil {
+		return Quantity{}, calculator.NewError("multiply", err, q, o)
+	}
+	r := Quantity{Value: v, Dim: dim}
+	// 2 * 5 km stays in km, m * m is printed in base units
+	if o.Dim == (Dimension{}) {
+		r.Unit = q.Unit
+	} else if q.Dim == (Dimension{}) {
+		r.Unit = o.Unit
+	}
+	return r, nil
+}
+
+// Divide returns q / o, the exponents of o are subtracted: 2 GiB / 512 MiB is 4 without a unit, and km / h is m/s
+func (q Quantity) Divide(o Quantity) (Quantity, error) {
+	v, err := divide.CheckedFloat(q.Value, o.Value)
+	if err != nil {
+		return Quantity{}, err
+	}
+	dim, err := q.Dim.plus(o.Dim, -1)
+	if err != nil {
+		return Quantity{}, calculator.NewError("divide", err, q, o)
+	}
+	r := Quantity{Value: v, Dim: dim}
+	if o.Dim == (Dimension{}) {
+		r.Unit = q.Unit // 10 km / 2 is 5 km
+	}
+	return r, nil
+}
+
+// Negate returns -q
+func (q Quantity) Negate() Quantity {
+	q.Value = -q.Value
+	return q
+}
+
+// In returns the value of q in the unit, e.g 1.5 for 1500 m in km
+func (q Quantity) In(unit string) (float64, error) {
+	u, ok := unitTable[unit]
+	if !ok {
+		return 0, fmt.Errorf("%w %q", ErrUnknownUnit, unit)
+	}
+	if u.Dim != q.Dim {
+		return 0, fmt.Errorf("%w: %v cannot be converted to %s", ErrDimension, q, unit)
+	}
+	return q.Value / u.Factor, nil
+}
+
+// String prints q in its unit, e.g 5.3 km, or in base units when it has none, e.g 3 m/s or 4 m^2
+func (q Quantity) String() string {
+	if q.Unit != "" {
+		v, _ := q.In(q.Unit)
+		return strconv.FormatFloat(v, 'g', -1, 64) + " " + q.Unit
+	}
+	v := strconv.FormatFloat(q.Value, 'g', -1, 64)
+	if q.Dim == (Dimension{}) {
+		return v
+	}
+	return v + " " + q.Dim.String()
+}
+
+// String writes the base units of d, units with a positive exponent first and the others after a /, e.g m/s^2
+func (d Dimension) String() string {
+	var num, den []string
+	for i, e := range d {
+		switch {
+		case e == 1:
+			num = append(num, baseUnits[i])
+		case e > 1:
+			num = append(num, fmt.Sprintf("%s^%d", baseUnits[i], e))
+		case e == -1:
+			den = append(den, baseUnits[i])
+		case e < -1:
+			den = append(den, fmt.Sprintf("%s^%d", baseUnits[i], -int(e))) // -e does not fit into an int8 for -128
+		}
+	}
+
+	s := strings.Join(num, "*")
+	if s == "" {
+		s = "1"
+	}
+	if len(den) > 0 {
+		s += "/" + strings.Join(den, "/")
+	}
+	return s
+}
+
+// plus returns d with the exponents of o added, or subtracted when sign is negative
+// An exponent is an int8, so m multiplied by itself 128 times would wrap around to 1/m^128 without the checks
+func (d Dimension) plus(o Dimension, sign int8) (Dimension, error) {
+	for i := range d {
+		var err error
+		if sign < 0 {
+			d[i], err = subtract.Checked(d[i], o[i])
+		} else {
+			d[i], err = add.Checked(d[i], o[i])
+		}
+		if err != nil {
+			return Dimension{}, fmt.Errorf("%w: the exponent of %s does not fit into an int8", calculator.ErrOverflow, baseUnits[i])
+		}
+	}
+	return d, nil
+}
+
+// firstUnit returns the unit of q, or of o when q has none
+func firstUnit(q, o Quantity) string {
+	if q.Unit != "" {
+		return q.Unit
+	}
+	return o.Unit
+}
@@ -0,0 +1,112 @@
+package units
+
+import (
+	"errors"
+	"testing"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+)
+
+// quantity returns New(value, unit) and fails the test on an error
+func quantity(t *testing.T, value float64, unit string) Quantity {
+	t.Helper()
+	q, err := New(value, unit)
+	if err != nil {
+		t.Fatal(err)
+	}
+	return q
+}
+
+func TestQuantityArithmetic(t *testing.T) {
+	km, m := quantity(t, 5, "km"), quantity(t, 300, "m")
+	gib, mib := quantity(t, 2, "GiB"), quantity(t, 512, "MiB")
+	hour := quantity(t, 2, "h")
+
+	for _, tt := range []struct {
+		name string
+		op   func(Quantity, Quantity) (Quantity, error)
+		a, b Quantity
+		want string
+	}{
+		{"5 km + 300 m", Quantity.Add, km, m, "5.3 km"},
+		{"300 m + 5 km", Quantity.Add, m, km, "5300 m"},
+		{"5 km - 300 m", Quantity.Subtract, km, m, "4.7 km"},
+		{"2 * 5 km", Quantity.Multiply, Number(2), km, "10 km"},
+		{"5 km * 300 m", Quantity.Multiply, km, m, "1.5e+06 m^2"},
+		{"5 km / 2", Quantity.Divide, km, Number(2), "2.5 km"},
+		{"2 GiB / 512 MiB", Quantity.Divide, gib, mib, "4"},
+		{"7.2 km / 2 h", Quantity.Divide, quantity(t, 7.2, "km"), hour, "1 m/s"},
+		{"6 / 2 s", Quantity.Divide, Number(6), quantity(t, 2, "s"), "3 1/s"},
+	} {
+		got, err := tt.op(tt.a, tt.b)
+		if err != nil {
+			t.Errorf("%s: %v", tt.name, err)
+			continue
+		}
+		if got.String() != tt.want {
+			t.Errorf("%s = %s, want %s", tt.name, got, tt.want)
+		}
+	}
+}
+
+func TestQuantityDimensionsMustMatch(t *testing.T) {
+	km, s := quantity(t, 5, "km"), quantity(t, 3, "s")
+	if _, err := km.Add(s); !errors.Is(err, ErrDimension) {
+		t.Errorf("5 km + 3 s err = %v, want ErrDimension", err)
+	}
+	if _, err := km.Subtract(Number(1)); !errors.Is(err, ErrDimension) {
+		t.Errorf("5 km - 1 err = %v, want ErrDimension", err)
+	}
+	if _, err := km.In("h"); !errors.Is(err, ErrDimension) {
+		t.Errorf("5 km in h err = %v, want ErrDimension", err)
+	}
+	if _, err := New(1, "parsec"); !errors.Is(err, ErrUnknownUnit) {
+		t.Errorf("New(1, parsec) err = %v, want ErrUnknownUnit", err)
+	}
+}
+
+func TestDimensionString(t *testing.T) {
+	for _, tt := range []struct {
+		dim  Dimension
+		want string
+	}{
+		{Dimension{}, "1"},
+		{Dimension{Length: 1}, "m"},
+		{Dimension{Length: 2}, "m^2"},
+		{Dimension{Length: 1, Time: -1}, "m/s"},
+		{Dimension{Length: 1, Time: -2}, "m/s^2"},
+		{Dimension{Time: -1}, "1/s"},
+		{Dimension{Length: 1, Mass: 1, Time: -2}, "m*kg/s^2"},
+		{Dimension{Data: 1, Time: -1, Length: -1}, "B/m/s"},
+		{Dimension{Length: -128}, "1/m^128"},
+	} {
+		if got := tt.dim.String(); got != tt.want {
+			t.Errorf("%v.String() = %q, want %q", [numDimensions]int8(tt.dim), got, tt.want)
+		}
+	}
+}
+
+func TestDimensionExponentOverflow(t *testing.T) {
+	m := quantity(t, 1, "m")
+
+	// m^127 is the largest power an int8 holds, one more m does not wrap around to 1/m^128
+	q := m
+	for i := 1; i < 127; i++ {
+		var err error
+		if q, err = q.Multiply(m); err != nil {
+			t.Fatalf("m^%d: %v", i+1, err)
+		}
+	}
+	if q.Dim != (Dimension{Length: 127}) {
+		t.Fatalf("dimension %s, want m^127", q.Dim)
+	}
+	if _, err := q.Multiply(m); !errors.Is(err, calculator.ErrOverflow) {
+		t.Errorf("m^127 * m err = %v, want ErrOverflow", err)
+	}
+	if _, err := Number(1).Divide(q); err != nil {
+		t.Errorf("1 / m^127 err = %v, want no error", err)
+	}
+	if _, err := q.Divide(Quantity{Value: 1, Dim: Dimension{Length: -1}}); !errors.Is(err, calculator.ErrOverflow) {
+		t.Errorf("m^127 / (1/m) err = %v, want ErrOverflow", err)
+	}
+}
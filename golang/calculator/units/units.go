@@ -0,0 +1,48 @@
+// Package units implements quantities with units, like 5 km or 2 GiB, in two ways:
+//
+// Named types check units when the program is compiled: Meters and Seconds are both float64 underneath,
+// but they are distinct types, so adding Meters to Seconds does not compile, like a DayOfMonth and a Weekday
+// in types.custom cannot be mixed
+//
+// Quantity checks units when the program runs: the calculator reads units from the expression that is typed,
+// which is only known at runtime, so a Quantity carries its dimension and operations compare dimensions
+package units
+
+// Named types for quantities, the value is in the base unit of the type
+type (
+	Meters    float64
+	Seconds   float64
+	Kilograms float64
+	Bytes     float64
+)
+
+// Units of the named types, 5 * Kilometer is 5000 Meters
+const (
+	Millimeter Meters = 0.001
+	Centimeter Meters = 0.01
+	Meter      Meters = 1
+	Kilometer  Meters = 1000
+
+	Millisecond Seconds = 0.001
+	Second      Seconds = 1
+	Minute      Seconds = 60
+	Hour        Seconds = 3600
+
+	Gram     Kilograms = 0.001
+	Kilogram Kilograms = 1
+
+	Byte Bytes = 1
+	KiB  Bytes = 1024 // Kibibyte, 2^10 bytes
+	MiB  Bytes = 1024 * KiB
+	GiB  Bytes = 1024 * MiB
+	KB   Bytes = 1000 // Kilobyte, 10^3 bytes, a KB is a bit smaller than a KiB
+	MB   Bytes = 1000 * KB
+	GB   Bytes = 1000 * MB
+)
+
+// Speed divides a distance by a time
+// Meters / Seconds does not compile because the types differ, so both are converted to float64 first
+// The result is a plain float64, named types do not tell that it is in meters per second, see Quantity for that
+func Speed(d Meters, t Seconds) float64 {
+	return float64(d) / float64(t)
+}
@@ -0,0 +1,27 @@
+package main
+
+import (
+	// These are standard packages provided by Golang
+	"fmt"     // Package fmt implements formatting operations on the console like printing, reading input, etc
+	"os"      // Package os gives access to the command line arguments and the standard error of the program
+	"strings" // Package strings implements functions to work with strings, like strings.Join
+
+	// These are custom packages defined by us
+	"github.com/shashank-priyadarshi/training/types" // Importing the lessons about the data types of Go
+)
+
+// Lessons
+// go run ./cmd/lessons slices runs the slices lesson of the types package
+// go run ./cmd/lessons all runs every lesson, in the order they are taught
+// go run ./cmd/lessons lists the lessons
+func main() {
+	if len(os.Args) < 2 {
+		fmt.Println("usage: lessons <name>, where name is one of", strings.Join(types.Lessons(), ", "), "or all")
+		return
+	}
+
+	if err := types.Run(os.Args[1]); err != nil {
+		fmt.Fprintln(os.Stderr, err)
+		os.Exit(1)
+	}
+}
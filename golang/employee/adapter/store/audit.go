@@ -0,0 +1,154 @@
+package store
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io/fs"
+	"os"
+	"sync"
+	"time"
+
+	"github.com/shashank-priyadarshi/training/employee"
+	"github.com/shashank-priyadarshi/training/types"
+)
+
+// MemoryAudit is an employee.AuditLog kept in memory, it is safe for concurrent use
+type MemoryAudit struct {
+	mu      sync.Mutex
+	changes []employee.LevelChange
+	nextID  int
+}
+
+// NewMemoryAudit returns an empty audit log
+func NewMemoryAudit() *MemoryAudit {
+	return &MemoryAudit{nextID: 1}
+}
+
+func (m *MemoryAudit) Add(change employee.LevelChange) (employee.LevelChange, error) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+
+	change.ID = m.nextID
+	m.nextID++
+	m.changes = append(m.changes, change)
+	return change, nil
+}
+
+func (m *MemoryAudit) List(employeeID int) ([]employee.LevelChange, error) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+
+	return changesOf(m.changes, employeeID), nil
+}
+
+// changeRecord is a level change as it is stored in the file, the JSON names are part of the file format
+type changeRecord struct {
+	ID         int         `json:"id"`
+	EmployeeID int         `json:"employee_id"`
+	From       types.Level `json:"from"`
+	To         types.Level `json:"to"`
+	Reason     string      `json:"reason"`
+	Override   bool        `json:"override,omitempty"`
+	Time       time.Time   `json:"time"`
+}
+
+// AuditFile is an employee.AuditLog kept in a JSON lines file, a change is appended as soon as it is added
+// Nothing is ever written over, which is what an audit log needs
+type AuditFile struct {
+	mu     sync.Mutex
+	path   string
+	nextID int
+}
+
+// OpenAuditFile returns the audit log stored at path, the file is created by the first Add when it does not exist
+func OpenAuditFile(path string) (*AuditFile, error) {
+	f := &AuditFile{path: path, nextID: 1}
+	changes, err := f.read()
+	if err != nil {
+		return nil, err
+	}
+	for _, c := range changes {
+		if c.ID >= f.nextID {
+			f.nextID = c.ID + 1
+		}
+	}
+	return f, nil
+}
+
+func (f *AuditFile) Add(change employee.LevelChange) (employee.LevelChange, error) {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+
+	change.ID = f.nextID
+	line, err := json.Marshal(changeRecord(change))
+	if err != nil {
+		return employee.LevelChange{}, err
+	}
+
+	file, err := os.OpenFile(f.path, os.O_APPEND|os.O_CREATE|os.O_WRONLY, 0o644)
+	if err != nil {
+		return employee.LevelChange{}, err
+	}
+	if _, err := file.Write(append(line, '\n')); err != nil {
+		file.Close()
+		return employee.LevelChange{}, err
+	}
+	if err := file.Close(); err != nil {
+		return employee.LevelChange{}, err
+	}
+
+	f.nextID++
+	return change, nil
+}
+
+func (f *AuditFile) List(employeeID int) ([]employee.LevelChange, error) {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+
+	changes, err := f.read()
+	if err != nil {
+		return nil, err
+	}
+	return changesOf(changes, employeeID), nil
+}
+
+// read returns all changes of the file, a missing file is an empty log
+func (f *AuditFile) read() ([]employee.LevelChange, error) {
+	file, err := os.Open(f.path)
+	if errors.Is(err, fs.ErrNotExist) {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, err
+	}
+	defer file.Close()
+
+	var changes []employee.LevelChange
+	scanner := newScanner(file)
+	for n := 1; scanner.Scan(); n++ {
+		if len(scanner.Bytes()) == 0 {
+			continue
+		}
+		var r changeRecord
+		if err := json.Unmarshal(scanner.Bytes(), &r); err != nil {
+			return nil, fmt.Errorf("%s:%d: %w", f.path, n, err)
+		}
+		changes = append(changes, employee.LevelChange(r))
+	}
+	if err := scanner.Err(); err != nil {
+		return nil, fmt.Errorf("%s: %w", f.path, err)
+	}
+	return changes, nil
+}
+
+// changesOf returns the changes of the employee
+func changesOf(changes []employee.LevelChange, employeeID int) []employee.LevelChange {
+	var found []employee.LevelChange
+	for _, c := range changes {
+		if c.EmployeeID == employeeID {
+			found = append(found, c)
+		}
+	}
+	return found
+}
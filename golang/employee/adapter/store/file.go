@@ -0,0 +1,213 @@
+package store
+
+import (
+	"bufio"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"io/fs"
+	"os"
+	"path/filepath"
+	"sync"
+	"time"
+
+	"github.com/shashank-priyadarshi/training/calculator/money"
+	"github.com/shashank-priyadarshi/training/types"
+)
+
+// record is an employee as it is stored in the file, the JSON names are part of the file format and must not change
+// It has the same fields as types.Employee, so that one converts into the other, a new field must be added to both
+type record struct {
+	ID           int                 `json:"id"`
+	Name         string              `json:"name"`
+	DateOfBirth  time.Time           `json:"date_of_birth"`
+	Level        types.Level         `json:"level"`
+	Salary       money.Money         `json:"salary"`
+	Address      types.Address       `json:"address"`
+	AddressSince time.Time           `json:"address_since"`
+	Permanent    types.Address       `json:"permanent_address"`
+	Previous     []types.PastAddress `json:"previous_addresses,omitempty"`
+}
+
+// counter is the last line of the file, the ID of the next employee
+// Without it, deleting the newest employee and opening the file again would give their ID to the next employee
+type counter struct {
+	NextID int `json:"next_id"`
+}
+
+// stored is a line of the file, either a record or a counter, only a counter has a NextID
+type stored struct {
+	record
+	counter
+}
+
+// maxLineSize is the longest line read from a file, a bufio.Scanner stops at 64 KiB unless it is given a larger
+// buffer, an employee with many previous addresses or a long reason for a level change can be longer
+const maxLineSize = 1 << 20
+
+// newScanner returns a scanner reading the lines of r, up to maxLineSize long
+func newScanner(r io.Reader) *bufio.Scanner {
+	scanner := bufio.NewScanner(r)
+	scanner.Buffer(nil, maxLineSize)
+	return scanner
+}
+
+// File keeps employees in a JSON lines file, the whole file is written again after every change
+// The new content is written to a temporary file that replaces the old one, so a crash never leaves half a file
+// It is safe for concurrent use within a program, but not by several programs sharing the file
+type File struct {
+	mu     sync.Mutex
+	path   string
+	nextID int
+}
+
+// OpenFile returns the employees stored at path, the file is created by the first Add when it does not exist
+func OpenFile(path string) (*File, error) {
+	f := &File{path: path}
+	_, nextID, err := f.read()
+	if err != nil {
+		return nil, err
+	}
+	f.nextID = nextID
+	return f, nil
+}
+
+func (f *File) Add(e types.Employee) (types.Employee, error) {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+
+	employees, _, err := f.read()
+	if err != nil {
+		return types.Employee{}, err
+	}
+	e.ID = f.nextID
+	if err := f.write(append(employees, e), f.nextID+1); err != nil {
+		return types.Employee{}, err
+	}
+	f.nextID++
+	return e, nil
+}
+
+func (f *File) Get(id int) (types.Employee, error) {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+
+	employees, _, err := f.read()
+	if err != nil {
+		return types.Employee{}, err
+	}
+	i, err := index(employees, id)
+	if err != nil {
+		return types.Employee{}, err
+	}
+	return employees[i], nil
+}
+
+func (f *File) Update(e types.Employee) error {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+
+	employees, _, err := f.read()
+	if err != nil {
+		return err
+	}
+	i, err := index(employees, e.ID)
+	if err != nil {
+		return err
+	}
+	employees[i] = e
+	return f.write(employees, f.nextID)
+}
+
+// Delete removes the employee, IDs are not reused, not even after the file is opened again
+func (f *File) Delete(id int) error {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+
+	employees, _, err := f.read()
+	if err != nil {
+		return err
+	}
+	i, err := index(employees, id)
+	if err != nil {
+		return err
+	}
+	return f.write(append(employees[:i], employees[i+1:]...), f.nextID)
+}
+
+func (f *File) List() ([]types.Employee, error) {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+
+	employees, _, err := f.read()
+	return employees, err
+}
+
+// read returns all employees of the file and the ID of the next employee, a missing file has no employees
+// A file written before the counter existed has none, the next ID follows the highest ID then
+func (f *File) read() ([]types.Employee, int, error) {
+	file, err := os.Open(f.path)
+	if errors.Is(err, fs.ErrNotExist) {
+		return nil, 1, nil
+	}
+	if err != nil {
+		return nil, 0, err
+	}
+	defer file.Close()
+
+	var employees []types.Employee
+	nextID := 1
+	scanner := newScanner(file)
+	for n := 1; scanner.Scan(); n++ {
+		if len(scanner.Bytes()) == 0 {
+			continue
+		}
+		var l stored
+		if err := json.Unmarshal(scanner.Bytes(), &l); err != nil {
+			return nil, 0, fmt.Errorf("%s:%d: %w", f.path, n, err)
+		}
+		if l.NextID == 0 {
+			employees = append(employees, types.Employee(l.record))
+			l.NextID = l.ID + 1
+		}
+		nextID = max(nextID, l.NextID)
+	}
+	if err := scanner.Err(); err != nil {
+		return nil, 0, fmt.Errorf("%s: %w", f.path, err)
+	}
+	return employees, nextID, nil
+}
+
+// write replaces the content of the file with the employees, followed by the ID of the next employee
+func (f *File) write(employees []types.Employee, nextID int) error {
+	tmp, err := os.CreateTemp(filepath.Dir(f.path), filepath.Base(f.path)+".*")
+	if err != nil {
+		return err
+	}
+	defer os.Remove(tmp.Name()) // Fails once the file was renamed, which is fine
+
+	w := bufio.NewWriter(tmp)
+	for _, e := range employees {
+		line, err := json.Marshal(record(e))
+		if err != nil {
+			tmp.Close()
+			return err
+		}
+		w.Write(append(line, '\n')) // A bufio.Writer keeps the first error, Flush returns it
+	}
+	line, err := json.Marshal(counter{NextID: nextID})
+	if err != nil {
+		tmp.Close()
+		return err
+	}
+	w.Write(append(line, '\n'))
+	if err := w.Flush(); err != nil {
+		tmp.Close()
+		return err
+	}
+	if err := tmp.Close(); err != nil {
+		return err
+	}
+	return os.Rename(tmp.Name(), f.path)
+}
@@ -0,0 +1,97 @@
+// Package store implements the employee.Repository port: Memory keeps the employees until the program exits,
+// and File keeps them in a file with one JSON object per line (JSON lines), so they survive restarts
+// MemoryAudit and AuditFile implement the employee.AuditLog port the same two ways
+package store
+
+import (
+	"sync"
+
+	"github.com/shashank-priyadarshi/training/employee"
+	"github.com/shashank-priyadarshi/training/types"
+)
+
+// Memory keeps employees in memory, ordered by ID, it is safe for concurrent use
+type Memory struct {
+	mu        sync.Mutex
+	employees []types.Employee
+	nextID    int
+}
+
+// NewMemory returns a repository without employees
+func NewMemory() *Memory {
+	return &Memory{nextID: 1}
+}
+
+func (m *Memory) Add(e types.Employee) (types.Employee, error) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+
+	e.ID = m.nextID
+	m.nextID++
+	m.employees = append(m.employees, clone(e))
+	return e, nil
+}
+
+func (m *Memory) Get(id int) (types.Employee, error) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+
+	i, err := index(m.employees, id)
+	if err != nil {
+		return types.Employee{}, err
+	}
+	return clone(m.employees[i]), nil
+}
+
+func (m *Memory) Update(e types.Employee) error {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+
+	i, err := index(m.employees, e.ID)
+	if err != nil {
+		return err
+	}
+	m.employees[i] = clone(e)
+	return nil
+}
+
+// Delete removes the employee, IDs are not reused
+func (m *Memory) Delete(id int) error {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+
+	i, err := index(m.employees, id)
+	if err != nil {
+		return err
+	}
+	m.employees = append(m.employees[:i], m.employees[i+1:]...)
+	return nil
+}
+
+func (m *Memory) List() ([]types.Employee, error) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+
+	employees := make([]types.Employee, len(m.employees))
+	for i, e := range m.employees {
+		employees[i] = clone(e)
+	}
+	return employees, nil
+}
+
+// index returns the position of the employee with the ID
+func index(employees []types.Employee, id int) (int, error) {
+	for i, e := range employees {
+		if e.ID == id {
+			return i, nil
+		}
+	}
+	return 0, employee.ErrNotFound
+}
+
+// clone returns a copy of e that shares no memory with e
+// Copying a struct copies its fields, but a slice field still points to the same array, so Previous is copied too
+func clone(e types.Employee) types.Employee {
+	e.Previous = append([]types.PastAddress(nil), e.Previous...)
+	return e
+}
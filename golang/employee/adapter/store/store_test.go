@@ -0,0 +1,156 @@
+package store_test
+
+import (
+	"errors"
+	"path/filepath"
+	"reflect"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/shashank-priyadarshi/training/calculator/money"
+	"github.com/shashank-priyadarshi/training/employee"
+	"github.com/shashank-priyadarshi/training/employee/adapter/store"
+	"github.com/shashank-priyadarshi/training/types"
+)
+
+// repositories returns a new, empty repository of every adapter, the same tests run against all of them
+func repositories(t *testing.T) map[string]employee.Repository {
+	file, err := store.OpenFile(filepath.Join(t.TempDir(), "employees.jsonl"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	return map[string]employee.Repository{"Memory": store.NewMemory(), "File": file}
+}
+
+func date(year int, month time.Month, day int) time.Time {
+	return time.Date(year, month, day, 0, 0, 0, 0, time.UTC)
+}
+
+func newEmployee(name string) types.Employee {
+	salary, _ := money.FromMajor(500_000, money.INR)
+	return types.Employee{
+		Name:         name,
+		DateOfBirth:  date(1990, time.May, 17),
+		Level:        types.Associate,
+		Salary:       salary,
+		Address:      types.Address{Street: "12 MG Road", City: "Bengaluru", State: "Karnataka", Pincode: "560001"},
+		AddressSince: date(2020, time.January, 1),
+		Permanent:    types.Address{City: "Patna", State: "Bihar"},
+		Previous: []types.PastAddress{
+			{Address: types.Address{City: "Pune", State: "Maharashtra"}, From: date(2015, time.June, 1), To: date(2020, time.January, 1)},
+		},
+	}
+}
+
+func TestRepository(t *testing.T) {
+	for name, repo := range repositories(t) {
+		t.Run(name, func(t *testing.T) {
+			var added []types.Employee
+			for i, n := range []string{"Asha", "Ravi", "Meera"} {
+				e, err := repo.Add(newEmployee(n))
+				if err != nil {
+					t.Fatal(err)
+				}
+				if e.ID != i+1 {
+					t.Errorf("Add(%s) ID = %d, want %d", n, e.ID, i+1)
+				}
+				added = append(added, e)
+			}
+
+			got, err := repo.Get(2)
+			if err != nil || !reflect.DeepEqual(got, added[1]) {
+				t.Errorf("Get(2) = %+v, %v, want %+v", got, err, added[1])
+			}
+			if _, err := repo.Get(4); !errors.Is(err, employee.ErrNotFound) {
+				t.Errorf("Get(4) err = %v, want ErrNotFound", err)
+			}
+
+			// The repository keeps its own copy, the slice of the previous addresses is not shared
+			got.Previous[0].City = "Delhi"
+			if again, _ := repo.Get(2); again.Previous[0].City != "Pune" {
+				t.Errorf("changing the employee returned by Get changed the stored one: %+v", again.Previous)
+			}
+
+			got.Level = types.Senior
+			if err := repo.Update(got); err != nil {
+				t.Fatal(err)
+			}
+			if updated, _ := repo.Get(2); !reflect.DeepEqual(updated, got) {
+				t.Errorf("Get(2) after Update = %+v, want %+v", updated, got)
+			}
+			if err := repo.Update(types.Employee{ID: 4, Name: "Nobody"}); !errors.Is(err, employee.ErrNotFound) {
+				t.Errorf("Update of ID 4 err = %v, want ErrNotFound", err)
+			}
+
+			// Deleting the newest employee does not give their ID to the next one
+			if err := repo.Delete(3); err != nil {
+				t.Fatal(err)
+			}
+			if err := repo.Delete(3); !errors.Is(err, employee.ErrNotFound) {
+				t.Errorf("second Delete(3) err = %v, want ErrNotFound", err)
+			}
+			e, err := repo.Add(newEmployee("Kiran"))
+			if err != nil || e.ID != 4 {
+				t.Errorf("Add after Delete = ID %d, %v, want ID 4, IDs are not reused", e.ID, err)
+			}
+
+			all, err := repo.List()
+			if err != nil {
+				t.Fatal(err)
+			}
+			var ids []int
+			for _, e := range all {
+				ids = append(ids, e.ID)
+			}
+			if !reflect.DeepEqual(ids, []int{1, 2, 4}) {
+				t.Errorf("List() IDs = %v, want [1 2 4] ordered by ID", ids)
+			}
+		})
+	}
+}
+
+func TestFileDoesNotReuseIDsAfterReopening(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "employees.jsonl")
+	file, err := store.OpenFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	_, _ = file.Add(newEmployee("Asha"))
+	_, _ = file.Add(newEmployee("Ravi"))
+	if err := file.Delete(2); err != nil {
+		t.Fatal(err)
+	}
+
+	reopened, err := store.OpenFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	e, err := reopened.Add(newEmployee("Meera"))
+	if err != nil || e.ID != 3 {
+		t.Errorf("Add after reopening = ID %d, %v, want ID 3, ID 2 belonged to Ravi", e.ID, err)
+	}
+}
+
+func TestFileReadsLongLines(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "employees.jsonl")
+	file, err := store.OpenFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	// Longer than the 64 KiB a bufio.Scanner reads by default
+	e := newEmployee("Asha")
+	e.Address.Street = strings.Repeat("Lane ", 1<<14)
+	if _, err := file.Add(e); err != nil {
+		t.Fatal(err)
+	}
+
+	reopened, err := store.OpenFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	all, err := reopened.List()
+	if err != nil || len(all) != 1 || all[0].Address.Street != e.Address.Street {
+		t.Fatalf("List() returned %d employees, %v, want the employee with the long street", len(all), err)
+	}
+}
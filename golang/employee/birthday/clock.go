@@ -0,0 +1,115 @@
+// Package birthday sends "Happy Birthday" messages to employees on their birthdays, see types.Employee.NotifyBirthday
+//
+// The clock and the way messages are sent are interfaces, types.Clock and types.Notifier, so they can be swapped:
+// SystemClock waits for real, ManualClock only moves when it is told to, which makes a year pass in an instant
+// Console, File and Webhook are the notifiers, Inbox receives the messages of Webhook on this machine
+package birthday
+
+import (
+	"context"
+	"slices"
+	"sync"
+	"time"
+)
+
+// SystemClock is the clock of the computer
+type SystemClock struct{}
+
+func (SystemClock) Now() time.Time {
+	return time.Now()
+}
+
+// After stops its timer when ctx is done, unlike time.After, whose timer runs until d has passed
+func (SystemClock) After(ctx context.Context, d time.Duration) <-chan time.Time {
+	ch := make(chan time.Time, 1)
+	timer := time.NewTimer(d)
+	go func() {
+		defer timer.Stop()
+		select {
+		case t := <-timer.C:
+			ch <- t
+		case <-ctx.Done():
+		}
+	}()
+	return ch
+}
+
+// ManualClock is a clock that stands still until Set or Advance moves it, it is safe for concurrent use
+// The channels returned by After receive the time once the clock has been moved past their deadline, or never when
+// their ctx is done first
+type ManualClock struct {
+	mu      sync.Mutex
+	now     time.Time
+	waiters []waiter
+}
+
+// waiter is a call to After that is waiting for the clock to reach at
+type waiter struct {
+	at   time.Time
+	ch   chan time.Time
+	stop func() bool // Stops removing the waiter when its ctx is done, see context.AfterFunc
+}
+
+// NewManualClock returns a clock that stands at now
+func NewManualClock(now time.Time) *ManualClock {
+	return &ManualClock{now: now}
+}
+
+func (c *ManualClock) Now() time.Time {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	return c.now
+}
+
+func (c *ManualClock) After(ctx context.Context, d time.Duration) <-chan time.Time {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+
+	// The channel has room for the time, so sending never blocks, even when nobody receives anymore
+	ch := make(chan time.Time, 1)
+	if d <= 0 {
+		ch <- c.now
+		return ch
+	}
+	stop := context.AfterFunc(ctx, func() { c.remove(ch) })
+	c.waiters = append(c.waiters, waiter{at: c.now.Add(d), ch: ch, stop: stop})
+	return ch
+}
+
+// remove forgets the waiter of ch, its ctx is done and nobody receives from ch anymore
+func (c *ManualClock) remove(ch chan time.Time) {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	c.waiters = slices.DeleteFunc(c.waiters, func(w waiter) bool { return w.ch == ch })
+}
+
+// Advance moves the clock forward by d
+func (c *ManualClock) Advance(d time.Duration) {
+	c.Set(c.Now().Add(d))
+}
+
+// Set moves the clock to now, and wakes up the calls to After whose deadline has passed
+func (c *ManualClock) Set(now time.Time) {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+
+	c.now = now
+	waiting := c.waiters[:0]
+	for _, w := range c.waiters {
+		if w.at.After(now) {
+			waiting = append(waiting, w)
+			continue
+		}
+		w.stop()
+		w.ch <- now
+	}
+	c.waiters = waiting
+}
+
+// Waiting returns the number of calls to After that wait for the clock to move, without the ones whose ctx is done
+// It tells when the goroutines of a Scheduler are all waiting, so that moving the clock wakes them all up
+func (c *ManualClock) Waiting() int {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	return len(c.waiters)
+}
@@ -0,0 +1,36 @@
+package birthday
+
+import (
+	"context"
+	"testing"
+	"time"
+)
+
+func TestManualClockForgetsCancelledWaits(t *testing.T) {
+	clock := NewManualClock(date(2024, time.January, 1))
+	ctx, cancel := context.WithCancel(context.Background())
+	cancelled := clock.After(ctx, time.Hour)
+	kept := clock.After(context.Background(), time.Hour)
+
+	cancel()
+	eventually(t, "the cancelled wait is removed", func() bool { return clock.Waiting() == 1 })
+	clock.Advance(time.Hour)
+	select {
+	case <-kept:
+	default:
+		t.Error("the wait that was not cancelled did not receive the time")
+	}
+	select {
+	case at := <-cancelled:
+		t.Errorf("the cancelled wait received %v, want nothing", at)
+	default:
+	}
+}
+
+func TestSystemClockAfter(t *testing.T) {
+	select {
+	case <-SystemClock{}.After(context.Background(), time.Millisecond):
+	case <-time.After(5 * time.Second):
+		t.Fatal("After(1ms) did not receive the time within 5s")
+	}
+}
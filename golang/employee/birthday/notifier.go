@@ -0,0 +1,121 @@
+package birthday
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"os"
+	"sync"
+	"time"
+
+	"github.com/shashank-priyadarshi/training/types"
+)
+
+// Console prints the messages, e.g to os.Stdout
+type Console struct {
+	W io.Writer
+}
+
+func (c Console) Notify(_ context.Context, e types.Employee, message string) error {
+	_, err := fmt.Fprintf(c.W, "to %s: %s\n", e.Name, message)
+	return err
+}
+
+// File appends the messages to a file, one line per message with the time it was sent
+type File struct {
+	Path  string
+	Clock types.Clock // Time written before every message, the system clock when nil
+}
+
+func (f File) Notify(_ context.Context, e types.Employee, message string) error {
+	clock := f.Clock
+	if clock == nil {
+		clock = SystemClock{}
+	}
+
+	file, err := os.OpenFile(f.Path, os.O_APPEND|os.O_CREATE|os.O_WRONLY, 0o644)
+	if err != nil {
+		return err
+	}
+	if _, err := fmt.Fprintf(file, "%s to %s (%d): %s\n", clock.Now().Format(time.RFC3339), e.Name, e.ID, message); err != nil {
+		file.Close()
+		return err
+	}
+	return file.Close()
+}
+
+// Message is the JSON body Webhook sends, and Inbox receives
+type Message struct {
+	EmployeeID int    `json:"employee_id"`
+	Name       string `json:"name"`
+	Message    string `json:"message"`
+}
+
+// Webhook sends the messages to a URL with an HTTP POST, the body is a Message in JSON
+// A chat application gives such a URL to post messages to a channel, Inbox stands in for it on this machine
+type Webhook struct {
+	URL    string
+	Client *http.Client // http.DefaultClient when nil
+}
+
+func (w Webhook) Notify(ctx context.Context, e types.Employee, message string) error {
+	client := w.Client
+	if client == nil {
+		client = http.DefaultClient
+	}
+
+	body, err := json.Marshal(Message{EmployeeID: e.ID, Name: e.Name, Message: message})
+	if err != nil {
+		return err
+	}
+	req, err := http.NewRequestWithContext(ctx, http.MethodPost, w.URL, bytes.NewReader(body))
+	if err != nil {
+		return err
+	}
+	req.Header.Set("Content-Type", "application/json")
+
+	resp, err := client.Do(req)
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode >= 300 {
+		return fmt.Errorf("webhook %s: %s", w.URL, resp.Status)
+	}
+	return nil
+}
+
+// Inbox is an HTTP handler that receives the messages of Webhook and keeps them, it is safe for concurrent use
+// Serve it with http.ListenAndServe(":8081", inbox) and point Webhook at http://localhost:8081
+type Inbox struct {
+	mu       sync.Mutex
+	messages []Message
+}
+
+func (in *Inbox) ServeHTTP(w http.ResponseWriter, r *http.Request) {
+	if r.Method != http.MethodPost {
+		w.Header().Set("Allow", http.MethodPost)
+		http.Error(w, "method not allowed", http.StatusMethodNotAllowed)
+		return
+	}
+	var m Message
+	if err := json.NewDecoder(r.Body).Decode(&m); err != nil {
+		http.Error(w, err.Error(), http.StatusBadRequest)
+		return
+	}
+
+	in.mu.Lock()
+	in.messages = append(in.messages, m)
+	in.mu.Unlock()
+	w.WriteHeader(http.StatusNoContent)
+}
+
+// Messages returns the messages received so far, oldest first
+func (in *Inbox) Messages() []Message {
+	in.mu.Lock()
+	defer in.mu.Unlock()
+	return append([]Message(nil), in.messages...)
+}
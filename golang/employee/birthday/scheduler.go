@@ -0,0 +1,59 @@
+package birthday
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"sort"
+	"sync"
+	"time"
+
+	"github.com/shashank-priyadarshi/training/types"
+)
+
+// Scheduler notifies many employees on their birthdays
+type Scheduler struct {
+	Clock    types.Clock
+	Notifier types.Notifier
+}
+
+// Run calls NotifyBirthday for every employee with a date of birth, each in its own goroutine, until ctx is cancelled
+// An employee whose message could not be sent is not notified anymore, the errors are returned when Run returns
+func (s Scheduler) Run(ctx context.Context, employees []types.Employee) error {
+	var wg sync.WaitGroup
+	errs := make([]error, len(employees)) // Every goroutine writes its own element, so no lock is needed
+	for i, e := range employees {
+		if e.DateOfBirth.IsZero() {
+			continue
+		}
+		wg.Add(1)
+		go func(i int, e types.Employee) {
+			defer wg.Done()
+			err := e.NotifyBirthday(ctx, s.Clock, s.Notifier)
+			if ctx.Err() != nil && errors.Is(err, ctx.Err()) {
+				return // Cancelled, this is how Run stops
+			}
+			errs[i] = fmt.Errorf("%s (%d): %w", e.Name, e.ID, err)
+		}(i, e)
+	}
+	wg.Wait()
+	return errors.Join(errs...)
+}
+
+// Birthday is the next birthday of an employee
+type Birthday struct {
+	Employee types.Employee
+	Date     time.Time
+}
+
+// Upcoming returns the next birthday of every employee with a date of birth, the closest first
+func Upcoming(employees []types.Employee, now time.Time) []Birthday {
+	var birthdays []Birthday
+	for _, e := range employees {
+		if !e.DateOfBirth.IsZero() {
+			birthdays = append(birthdays, Birthday{Employee: e, Date: e.NextBirthday(now)})
+		}
+	}
+	sort.SliceStable(birthdays, func(i, j int) bool { return birthdays[i].Date.Before(birthdays[j].Date) })
+	return birthdays
+}
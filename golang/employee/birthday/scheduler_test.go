@@ -0,0 +1,165 @@
+package birthday
+
+import (
+	"context"
+	"errors"
+	"strings"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/shashank-priyadarshi/training/types"
+)
+
+func date(year int, month time.Month, day int) time.Time {
+	return time.Date(year, month, day, 0, 0, 0, 0, time.UTC)
+}
+
+// sent is a message that was sent, with the time of the clock when it was sent
+type sent struct {
+	id int
+	at time.Time
+}
+
+// recorder is a types.Notifier that keeps what it sends, and fails for the employees in fail
+type recorder struct {
+	clock *ManualClock
+	fail  map[int]error
+	mu    sync.Mutex
+	sent  []sent
+}
+
+func (r *recorder) Notify(_ context.Context, e types.Employee, _ string) error {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	r.sent = append(r.sent, sent{id: e.ID, at: r.clock.Now()})
+	return r.fail[e.ID]
+}
+
+func (r *recorder) Sent() []sent {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	return append([]sent(nil), r.sent...)
+}
+
+// eventually waits until cond is true, the goroutines under test run on their own once the clock moved
+func eventually(t *testing.T, what string, cond func() bool) {
+	t.Helper()
+	for deadline := time.Now().Add(5 * time.Second); !cond(); time.Sleep(time.Millisecond) {
+		if time.Now().After(deadline) {
+			t.Fatalf("timed out waiting until %s", what)
+		}
+	}
+}
+
+// notify runs NotifyBirthday in a goroutine, the error it returns is sent on the channel
+func notify(ctx context.Context, e types.Employee, clock *ManualClock, r *recorder) <-chan error {
+	done := make(chan error, 1)
+	go func() { done <- e.NotifyBirthday(ctx, clock, r) }()
+	return done
+}
+
+// checkSent fails the test unless the recorder sent a message on every date, in order
+func checkSent(t *testing.T, r *recorder, dates ...time.Time) {
+	t.Helper()
+	got := r.Sent()
+	if len(got) != len(dates) {
+		t.Fatalf("sent %d messages %v, want %d", len(got), got, len(dates))
+	}
+	for i, d := range dates {
+		if !got[i].at.Equal(d) {
+			t.Errorf("message %d sent at %v, want %v", i+1, got[i].at, d)
+		}
+	}
+}
+
+func TestNotifyBirthdayEveryYear(t *testing.T) {
+	clock := NewManualClock(date(2024, time.May, 16).Add(12 * time.Hour))
+	r := &recorder{clock: clock}
+	ctx, cancel := context.WithCancel(context.Background())
+	done := notify(ctx, types.Employee{ID: 1, Name: "Asha", DateOfBirth: date(1990, time.May, 17)}, clock, r)
+
+	eventually(t, "the timer is started", func() bool { return clock.Waiting() == 1 })
+	clock.Set(date(2024, time.May, 17))
+	// After the message, the timer is started again for the birthday of the next year
+	eventually(t, "the message is sent", func() bool { return len(r.Sent()) == 1 && clock.Waiting() == 1 })
+	clock.Set(date(2025, time.May, 16))
+	clock.Set(date(2025, time.May, 17))
+	eventually(t, "the second message is sent", func() bool { return len(r.Sent()) == 2 && clock.Waiting() == 1 })
+
+	cancel()
+	if err := <-done; !errors.Is(err, context.Canceled) {
+		t.Errorf("NotifyBirthday err = %v, want context.Canceled", err)
+	}
+	// The timer of the birthday of 2026 is stopped, not left waiting for a year
+	eventually(t, "the timer is stopped", func() bool { return clock.Waiting() == 0 })
+	checkSent(t, r, date(2024, time.May, 17), date(2025, time.May, 17))
+}
+
+func TestNotifyBirthdayOnFebruary28(t *testing.T) {
+	clock := NewManualClock(date(2025, time.January, 1))
+	r := &recorder{clock: clock}
+	ctx, cancel := context.WithCancel(context.Background())
+	done := notify(ctx, types.Employee{ID: 1, Name: "Leap", DateOfBirth: date(2000, time.February, 29)}, clock, r)
+
+	// 2025 and 2026 have no February 29, 2028 has one
+	for _, d := range []time.Time{date(2025, time.February, 28), date(2026, time.February, 28), date(2028, time.February, 29)} {
+		eventually(t, "the timer is started", func() bool { return clock.Waiting() == 1 })
+		clock.Set(d.Add(-time.Hour))
+		clock.Set(d)
+	}
+	eventually(t, "the last message is sent", func() bool { return len(r.Sent()) == 3 && clock.Waiting() == 1 })
+
+	cancel()
+	<-done
+	checkSent(t, r, date(2025, time.February, 28), date(2026, time.February, 28), date(2028, time.February, 29))
+}
+
+func TestNotifyBirthdayStartedDuringTheBirthday(t *testing.T) {
+	// The birthday started at midnight, the message is sent at once instead of a year later
+	at := date(2024, time.May, 17).Add(10 * time.Hour)
+	clock := NewManualClock(at)
+	r := &recorder{clock: clock}
+	ctx, cancel := context.WithCancel(context.Background())
+	done := notify(ctx, types.Employee{ID: 1, Name: "Asha", DateOfBirth: date(1990, time.May, 17)}, clock, r)
+
+	eventually(t, "the timer is started for the next year", func() bool { return clock.Waiting() == 1 })
+	cancel()
+	<-done
+	checkSent(t, r, at)
+}
+
+func TestSchedulerStopsAnEmployeeWhoseMessageFails(t *testing.T) {
+	clock := NewManualClock(date(2024, time.January, 1))
+	failed := errors.New("mailbox full")
+	r := &recorder{clock: clock, fail: map[int]error{1: failed}}
+	employees := []types.Employee{
+		{ID: 1, Name: "Asha", DateOfBirth: date(1990, time.May, 17)},
+		{ID: 2, Name: "Ravi", DateOfBirth: date(1985, time.May, 17)},
+		// Without a date of birth there is nothing to schedule
+		{ID: 3, Name: "Nobody"},
+	}
+
+	ctx, cancel := context.WithCancel(context.Background())
+	done := make(chan error, 1)
+	go func() { done <- Scheduler{Clock: clock, Notifier: r}.Run(ctx, employees) }()
+
+	eventually(t, "both timers are started", func() bool { return clock.Waiting() == 2 })
+	clock.Set(date(2024, time.May, 17))
+	// Asha stops after the error, only Ravi waits for the next year
+	eventually(t, "both messages are tried", func() bool { return len(r.Sent()) == 2 && clock.Waiting() == 1 })
+	clock.Set(date(2025, time.May, 17))
+	eventually(t, "Ravi gets the message of the next year", func() bool { return len(r.Sent()) == 3 && clock.Waiting() == 1 })
+
+	cancel()
+	err := <-done
+	if !errors.Is(err, failed) || !strings.Contains(err.Error(), "Asha (1)") {
+		t.Errorf("Run err = %v, want the error of Asha", err)
+	}
+	if errors.Is(err, context.Canceled) {
+		t.Errorf("Run err = %v, the cancellation is how Run stops, it is not an error", err)
+	}
+	if last := r.Sent()[2]; last.id != 2 {
+		t.Errorf("the message of 2025 went to %d, want 2", last.id)
+	}
+}
@@ -0,0 +1,197 @@
+// Package employee is the core of the employee registry: employees are created, read, updated, deleted and listed
+// with filters and pages, like the calculator it follows the hexagonal (ports and adapters) architecture
+//
+// The domain is types.Employee, with its types.Address and its types.Level
+// The core stores employees through the Repository port, implemented by the adapters in employee/adapter/store:
+// one keeps the employees in memory and one in a file
+//
+// Filters and pages are applied by the core, so every repository only has to list all employees
+// Promotions and demotions are checked by the core and recorded through the AuditLog port
+// Package employee/birthday sends messages to the employees on their birthdays
+// Package employee/payroll computes monthly salaries, salary bands and raises
+package employee
+
+import (
+	"errors"
+	"fmt"
+	"slices"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/shashank-priyadarshi/training/types"
+)
+
+var (
+	// ErrNotFound is returned when no employee has the ID
+	ErrNotFound = errors.New("employee not found")
+	// ErrInvalid is returned when an employee cannot be stored, e.g without a name
+	ErrInvalid = errors.New("invalid employee")
+)
+
+// Repository is the port through which the core stores employees
+// Employees are values, a repository keeps its own copy, changing an employee after Add or Get changes nothing
+type Repository interface {
+	// Add stores the employee with a new ID and returns it
+	Add(e types.Employee) (types.Employee, error)
+	// Get returns the employee with the ID, or ErrNotFound
+	Get(id int) (types.Employee, error)
+	// Update replaces the employee with the same ID, or returns ErrNotFound
+	Update(e types.Employee) error
+	// Delete removes the employee with the ID, or returns ErrNotFound
+	Delete(id int) error
+	// List returns all employees, ordered by ID
+	List() ([]types.Employee, error)
+}
+
+// Filter selects employees, an empty field selects every employee
+// City and State are compared with the current address, ignoring case
+type Filter struct {
+	Levels []types.Level // Any of the levels
+	City   string
+	State  string
+}
+
+// Page selects a part of a list: page Number, counting from 1, with Size employees
+type Page struct {
+	Number int
+	Size   int
+}
+
+// DefaultPageSize and MaxPageSize are the page sizes used when Size is 0 or too large
+const (
+	DefaultPageSize = 20
+	MaxPageSize     = 100
+)
+
+// List is a page of employees
+type List struct {
+	Employees []types.Employee
+	Page      Page // The page that was returned, with the sizes filled in
+	Total     int  // Number of employees selected by the filter, on all pages
+	Pages     int  // Number of pages
+}
+
+// Service is the employee registry
+type Service struct {
+	repo  Repository
+	audit AuditLog
+	mu    sync.Mutex       // Held while a level changes, see changeLevel
+	now   func() time.Time // Time of the level changes, see SetClock
+}
+
+// NewService returns a registry storing its employees in repo, and their promotions and demotions in audit
+func NewService(repo Repository, audit AuditLog) *Service {
+	return &Service{repo: repo, audit: audit, now: time.Now}
+}
+
+// SetClock makes the registry take the time of the level changes from now instead of time.Now, e.g in tests
+func (s *Service) SetClock(now func() time.Time) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	s.now = now
+}
+
+// Create registers a new employee and returns it with its ID, the ID of e must be 0
+func (s *Service) Create(e types.Employee) (types.Employee, error) {
+	if e.ID != 0 {
+		return types.Employee{}, fmt.Errorf("%w: a new employee cannot have an ID, it has %d", ErrInvalid, e.ID)
+	}
+	if err := validate(e); err != nil {
+		return types.Employee{}, err
+	}
+	return s.repo.Add(e)
+}
+
+// Get returns the employee with the ID
+func (s *Service) Get(id int) (types.Employee, error) {
+	return s.repo.Get(id)
+}
+
+// Update replaces the stored employee with the same ID as e
+// The level cannot be changed by Update, promotions and demotions go through Promote, Demote and ChangeLevel
+func (s *Service) Update(e types.Employee) error {
+	if err := validate(e); err != nil {
+		return err
+	}
+
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	stored, err := s.repo.Get(e.ID)
+	if err != nil {
+		return err
+	}
+	if stored.Level != e.Level {
+		return fmt.Errorf("%w: Update cannot change the level from %v to %v, use Promote, Demote or ChangeLevel", ErrLevelChange, stored.Level, e.Level)
+	}
+	return s.repo.Update(e)
+}
+
+// Delete removes the employee with the ID
+func (s *Service) Delete(id int) error {
+	return s.repo.Delete(id)
+}
+
+// List returns a page of the employees selected by filter, ordered by ID
+// A page after the last one is empty, it is not an error
+func (s *Service) List(filter Filter, page Page) (List, error) {
+	all, err := s.repo.List()
+	if err != nil {
+		return List{}, err
+	}
+
+	var selected []types.Employee
+	for _, e := range all {
+		if filter.match(e) {
+			selected = append(selected, e)
+		}
+	}
+
+	if page.Number < 1 {
+		page.Number = 1
+	}
+	if page.Size < 1 {
+		page.Size = DefaultPageSize
+	}
+	if page.Size > MaxPageSize {
+		page.Size = MaxPageSize
+	}
+
+	// A page after the last one starts at the end, the check comes first so that a huge page number cannot overflow
+	start := len(selected)
+	if page.Number-1 <= len(selected)/page.Size {
+		start = min((page.Number-1)*page.Size, len(selected))
+	}
+	end := min(start+page.Size, len(selected))
+	return List{
+		Employees: selected[start:end],
+		Page:      page,
+		Total:     len(selected),
+		Pages:     (len(selected) + page.Size - 1) / page.Size,
+	}, nil
+}
+
+func (f Filter) match(e types.Employee) bool {
+	if len(f.Levels) > 0 && !slices.Contains(f.Levels, e.Level) {
+		return false
+	}
+	if f.City != "" && !strings.EqualFold(f.City, e.Address.City) {
+		return false
+	}
+	return f.State == "" || strings.EqualFold(f.State, e.Address.State)
+}
+
+// validate checks the fields every stored employee must have
+func validate(e types.Employee) error {
+	if strings.TrimSpace(e.Name) == "" {
+		return fmt.Errorf("%w: the name is empty", ErrInvalid)
+	}
+	if !e.Level.Valid() {
+		return fmt.Errorf("%w: %v is not between %v and %v", ErrInvalid, e.Level, types.Fresher, types.CEO)
+	}
+	if e.Salary.Sign() < 0 {
+		return fmt.Errorf("%w: the salary %v is negative", ErrInvalid, e.Salary)
+	}
+	return nil
+}
@@ -0,0 +1,122 @@
+package employee_test
+
+import (
+	"math"
+	"reflect"
+	"testing"
+	"time"
+
+	"github.com/shashank-priyadarshi/training/calculator/money"
+	"github.com/shashank-priyadarshi/training/employee"
+	"github.com/shashank-priyadarshi/training/employee/adapter/store"
+	"github.com/shashank-priyadarshi/training/types"
+)
+
+// newEmployee returns an employee that can be created, living in the city
+func newEmployee(name string, level types.Level, city, state string) types.Employee {
+	salary, _ := money.FromMajor(500_000, money.INR)
+	return types.Employee{
+		Name:        name,
+		DateOfBirth: time.Date(1990, time.May, 17, 0, 0, 0, 0, time.UTC),
+		Level:       level,
+		Salary:      salary,
+		Address:     types.Address{City: city, State: state},
+	}
+}
+
+// newService returns a registry in memory with the employees, their IDs count from 1 in the order given
+func newService(t *testing.T, employees ...types.Employee) *employee.Service {
+	t.Helper()
+	s := employee.NewService(store.NewMemory(), store.NewMemoryAudit())
+	for _, e := range employees {
+		if _, err := s.Create(e); err != nil {
+			t.Fatal(err)
+		}
+	}
+	return s
+}
+
+// ids returns the IDs of the employees
+func ids(employees []types.Employee) []int {
+	ids := []int{}
+	for _, e := range employees {
+		ids = append(ids, e.ID)
+	}
+	return ids
+}
+
+func TestListPages(t *testing.T) {
+	var employees []types.Employee
+	for i := 0; i < 5; i++ {
+		employees = append(employees, newEmployee("Asha", types.Associate, "Pune", "Maharashtra"))
+	}
+	s := newService(t, employees...)
+
+	for _, tt := range []struct {
+		name  string
+		page  employee.Page
+		ids   []int
+		want  employee.Page // The page with the sizes filled in
+		pages int
+	}{
+		{"first page", employee.Page{Number: 1, Size: 2}, []int{1, 2}, employee.Page{Number: 1, Size: 2}, 3},
+		{"last page", employee.Page{Number: 3, Size: 2}, []int{5}, employee.Page{Number: 3, Size: 2}, 3},
+		{"past the end", employee.Page{Number: 4, Size: 2}, []int{}, employee.Page{Number: 4, Size: 2}, 3},
+		{"far past the end", employee.Page{Number: math.MaxInt, Size: 2}, []int{}, employee.Page{Number: math.MaxInt, Size: 2}, 3},
+		{"page 0 is the first page", employee.Page{Size: 2}, []int{1, 2}, employee.Page{Number: 1, Size: 2}, 3},
+		{"size 0 is the default size", employee.Page{Number: 1}, []int{1, 2, 3, 4, 5}, employee.Page{Number: 1, Size: employee.DefaultPageSize}, 1},
+		{"negative size", employee.Page{Number: 1, Size: -3}, []int{1, 2, 3, 4, 5}, employee.Page{Number: 1, Size: employee.DefaultPageSize}, 1},
+		{"size above the maximum", employee.Page{Number: 1, Size: 1000}, []int{1, 2, 3, 4, 5}, employee.Page{Number: 1, Size: employee.MaxPageSize}, 1},
+		{"one per page", employee.Page{Number: 5, Size: 1}, []int{5}, employee.Page{Number: 5, Size: 1}, 5},
+	} {
+		list, err := s.List(employee.Filter{}, tt.page)
+		if err != nil {
+			t.Fatalf("%s: %v", tt.name, err)
+		}
+		if got := ids(list.Employees); !reflect.DeepEqual(got, tt.ids) {
+			t.Errorf("%s: IDs %v, want %v", tt.name, got, tt.ids)
+		}
+		if list.Page != tt.want || list.Total != 5 || list.Pages != tt.pages {
+			t.Errorf("%s: page %+v, total %d, pages %d, want %+v, 5, %d", tt.name, list.Page, list.Total, list.Pages, tt.want, tt.pages)
+		}
+	}
+
+	empty := newService(t)
+	if list, err := empty.List(employee.Filter{}, employee.Page{}); err != nil || len(list.Employees) != 0 || list.Pages != 0 {
+		t.Errorf("List of no employees = %+v, %v, want no employees and no pages", list, err)
+	}
+}
+
+func TestListFilters(t *testing.T) {
+	s := newService(t,
+		newEmployee("Asha", types.Fresher, "Pune", "Maharashtra"),
+		newEmployee("Ravi", types.Senior, "Mumbai", "Maharashtra"),
+		newEmployee("Meera", types.Lead, "Bengaluru", "Karnataka"),
+		newEmployee("Kiran", types.Senior, "Bengaluru", "Karnataka"),
+		newEmployee("Dev", types.CEO, "Pune", "Maharashtra"),
+	)
+
+	for _, tt := range []struct {
+		name   string
+		filter employee.Filter
+		ids    []int
+	}{
+		{"no filter", employee.Filter{}, []int{1, 2, 3, 4, 5}},
+		{"one level", employee.Filter{Levels: []types.Level{types.Senior}}, []int{2, 4}},
+		{"any of the levels", employee.Filter{Levels: []types.Level{types.Fresher, types.CEO}}, []int{1, 5}},
+		{"city ignoring case", employee.Filter{City: "bengaluru"}, []int{3, 4}},
+		{"state ignoring case", employee.Filter{State: "MAHARASHTRA"}, []int{1, 2, 5}},
+		{"level and city", employee.Filter{Levels: []types.Level{types.Senior}, City: "Bengaluru"}, []int{4}},
+		{"city and state", employee.Filter{City: "Pune", State: "Maharashtra"}, []int{1, 5}},
+		{"city in another state", employee.Filter{City: "Pune", State: "Karnataka"}, []int{}},
+		{"no such level", employee.Filter{Levels: []types.Level{types.VP}}, []int{}},
+	} {
+		list, err := s.List(tt.filter, employee.Page{Number: 1})
+		if err != nil {
+			t.Fatalf("%s: %v", tt.name, err)
+		}
+		if got := ids(list.Employees); !reflect.DeepEqual(got, tt.ids) || list.Total != len(tt.ids) {
+			t.Errorf("%s: IDs %v, total %d, want %v", tt.name, got, list.Total, tt.ids)
+		}
+	}
+}
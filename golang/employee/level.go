@@ -0,0 +1,110 @@
+package employee
+
+import (
+	"errors"
+	"fmt"
+	"time"
+
+	"github.com/shashank-priyadarshi/training/types"
+)
+
+// ErrLevelChange is returned when a promotion or a demotion is not allowed
+var ErrLevelChange = errors.New("level change not allowed")
+
+// LevelChange is a promotion or a demotion recorded in the audit log
+type LevelChange struct {
+	ID         int // Assigned by the audit log, counting from 1
+	EmployeeID int
+	From       types.Level
+	To         types.Level
+	Reason     string
+	Override   bool // The change skipped levels, which needs an override
+	Time       time.Time
+}
+
+// Promotion reports whether the change is a promotion, it is a demotion otherwise
+func (c LevelChange) Promotion() bool {
+	return c.To > c.From
+}
+
+// AuditLog is the port through which the core records level changes, implemented by the adapters in
+// employee/adapter/store like Repository
+// Changes are only ever added, never updated or removed, so the log tells how every employee got to their level
+type AuditLog interface {
+	// Add records the change with a new ID and returns it
+	Add(change LevelChange) (LevelChange, error)
+	// List returns the changes of the employee, oldest first
+	List(employeeID int) ([]LevelChange, error)
+}
+
+// Promote moves the employee one level up, a CEO cannot be promoted
+func (s *Service) Promote(id int, reason string) (LevelChange, error) {
+	return s.changeLevel(id, func(from types.Level) types.Level { return from + 1 }, reason, false)
+}
+
+// Demote moves the employee one level down, a Fresher cannot be demoted
+func (s *Service) Demote(id int, reason string) (LevelChange, error) {
+	return s.changeLevel(id, func(from types.Level) types.Level { return from - 1 }, reason, false)
+}
+
+// ChangeLevel moves the employee to the level, skipping more than one level up or down needs override
+func (s *Service) ChangeLevel(id int, to types.Level, reason string, override bool) (LevelChange, error) {
+	return s.changeLevel(id, func(types.Level) types.Level { return to }, reason, override)
+}
+
+// LevelHistory returns the level changes of the employee, oldest first
+func (s *Service) LevelHistory(id int) ([]LevelChange, error) {
+	if _, err := s.repo.Get(id); err != nil {
+		return nil, err
+	}
+	return s.audit.List(id)
+}
+
+// changeLevel moves the employee to the level returned by to, which gets the current level
+// The lock makes reading the employee, checking the change and storing it one step, so two promotions at the same time
+// cannot both start from the same level
+func (s *Service) changeLevel(id int, to func(from types.Level) types.Level, reason string, override bool) (LevelChange, error) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	e, err := s.repo.Get(id)
+	if err != nil {
+		return LevelChange{}, err
+	}
+	change := LevelChange{EmployeeID: id, From: e.Level, To: to(e.Level), Reason: reason, Override: override, Time: s.now()}
+	if err := checkLevelChange(change); err != nil {
+		return LevelChange{}, err
+	}
+
+	// The audit log cannot take an entry back, so the employee is updated first, and put back when the change
+	// cannot be recorded: an employee is never at a level the audit log does not explain
+	updated := e
+	updated.Level = change.To
+	if err := s.repo.Update(updated); err != nil {
+		return LevelChange{}, err
+	}
+	recorded, err := s.audit.Add(change)
+	if err != nil {
+		if undoErr := s.repo.Update(e); undoErr != nil {
+			return LevelChange{}, errors.Join(err, fmt.Errorf("the employee stays %v, undoing the change failed: %w", change.To, undoErr))
+		}
+		return LevelChange{}, err
+	}
+	return recorded, nil
+}
+
+func checkLevelChange(c LevelChange) error {
+	switch {
+	case !c.To.Valid() && c.To > types.CEO:
+		return fmt.Errorf("%w: %v is the highest level", ErrLevelChange, types.CEO)
+	case !c.To.Valid():
+		return fmt.Errorf("%w: %v is the lowest level", ErrLevelChange, types.Fresher)
+	case c.To == c.From:
+		return fmt.Errorf("%w: the employee is already %v", ErrLevelChange, c.From)
+	case !c.Override && (c.To-c.From > 1 || c.From-c.To > 1):
+		return fmt.Errorf("%w: %v to %v skips levels, which needs an override", ErrLevelChange, c.From, c.To)
+	case c.Reason == "":
+		return fmt.Errorf("%w: a reason is needed", ErrLevelChange)
+	}
+	return nil
+}
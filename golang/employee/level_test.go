@@ -0,0 +1,163 @@
+package employee_test
+
+import (
+	"errors"
+	"testing"
+	"time"
+
+	"github.com/shashank-priyadarshi/training/employee"
+	"github.com/shashank-priyadarshi/training/employee/adapter/store"
+	"github.com/shashank-priyadarshi/training/types"
+)
+
+// levelService returns a registry with a Fresher, 1, a Senior, 2, and a CEO, 3
+func levelService(t *testing.T) *employee.Service {
+	return newService(t,
+		newEmployee("Asha", types.Fresher, "Pune", "Maharashtra"),
+		newEmployee("Ravi", types.Senior, "Pune", "Maharashtra"),
+		newEmployee("Dev", types.CEO, "Pune", "Maharashtra"),
+	)
+}
+
+// checkUnchanged fails the test unless the employees of levelService kept their levels, with no change recorded
+func checkUnchanged(t *testing.T, s *employee.Service, name string) {
+	t.Helper()
+	for id, level := range map[int]types.Level{1: types.Fresher, 2: types.Senior, 3: types.CEO} {
+		if e, _ := s.Get(id); e.Level != level {
+			t.Errorf("%s: employee %d is %v, want %v", name, id, e.Level, level)
+		}
+		if changes, _ := s.LevelHistory(id); len(changes) != 0 {
+			t.Errorf("%s: employee %d has the level changes %+v, want none", name, id, changes)
+		}
+	}
+}
+
+func TestLevelChangeRules(t *testing.T) {
+	for _, tt := range []struct {
+		name     string
+		id       int
+		to       types.Level
+		reason   string
+		override bool
+		want     error
+	}{
+		{"skip a level up", 2, types.Manager, "great year", false, employee.ErrLevelChange},
+		{"skip a level down", 2, types.Fresher, "reorg", false, employee.ErrLevelChange},
+		{"the same level", 2, types.Senior, "no change", true, employee.ErrLevelChange},
+		{"no reason", 2, types.Lead, "", false, employee.ErrLevelChange},
+		// An override skips levels, it does not leave them
+		{"past CEO", 3, types.CEO + 1, "great year", true, employee.ErrLevelChange},
+		{"below Fresher", 1, types.Fresher - 1, "reorg", true, employee.ErrLevelChange},
+		{"unknown employee", 9, types.Lead, "great year", false, employee.ErrNotFound},
+	} {
+		s := levelService(t)
+		if _, err := s.ChangeLevel(tt.id, tt.to, tt.reason, tt.override); !errors.Is(err, tt.want) {
+			t.Errorf("%s: err = %v, want %v", tt.name, err, tt.want)
+		}
+		checkUnchanged(t, s, tt.name)
+	}
+
+	s := levelService(t)
+	if _, err := s.Promote(3, "great year"); !errors.Is(err, employee.ErrLevelChange) {
+		t.Errorf("Promote of a CEO err = %v, want ErrLevelChange", err)
+	}
+	if _, err := s.Demote(1, "reorg"); !errors.Is(err, employee.ErrLevelChange) {
+		t.Errorf("Demote of a Fresher err = %v, want ErrLevelChange", err)
+	}
+	checkUnchanged(t, s, "Promote and Demote")
+
+	// With an override, levels can be skipped
+	if c, err := s.ChangeLevel(2, types.Architect, "acting architect", true); err != nil || !c.Override {
+		t.Errorf("ChangeLevel with an override = %+v, %v, want a recorded override", c, err)
+	}
+}
+
+func TestLevelChangesAreRecorded(t *testing.T) {
+	s := newService(t, newEmployee("Asha", types.Fresher, "Pune", "Maharashtra"))
+	at := time.Date(2024, time.April, 1, 9, 0, 0, 0, time.UTC)
+	s.SetClock(func() time.Time { return at })
+
+	if _, err := s.Promote(1, "first year"); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := s.ChangeLevel(1, types.Lead, "acting lead", true); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := s.Demote(1, "reorg"); err != nil {
+		t.Fatal(err)
+	}
+
+	changes, err := s.LevelHistory(1)
+	if err != nil {
+		t.Fatal(err)
+	}
+	want := []employee.LevelChange{
+		{ID: 1, EmployeeID: 1, From: types.Fresher, To: types.Associate, Reason: "first year", Time: at},
+		{ID: 2, EmployeeID: 1, From: types.Associate, To: types.Lead, Reason: "acting lead", Override: true, Time: at},
+		{ID: 3, EmployeeID: 1, From: types.Lead, To: types.Senior, Reason: "reorg", Time: at},
+	}
+	if len(changes) != len(want) {
+		t.Fatalf("LevelHistory(1) = %+v, want %d changes", changes, len(want))
+	}
+	for i, w := range want {
+		if changes[i] != w {
+			t.Errorf("change %d = %+v, want %+v", i+1, changes[i], w)
+		}
+	}
+	if e, _ := s.Get(1); e.Level != types.Senior {
+		t.Errorf("level %v, want %v", e.Level, types.Senior)
+	}
+	if _, err := s.LevelHistory(9); !errors.Is(err, employee.ErrNotFound) {
+		t.Errorf("LevelHistory(9) err = %v, want ErrNotFound", err)
+	}
+}
+
+// errDiskFull is the error of failingAudit
+var errDiskFull = errors.New("disk full")
+
+// failingAudit is an audit log that cannot record anything
+type failingAudit struct{}
+
+func (failingAudit) Add(employee.LevelChange) (employee.LevelChange, error) {
+	return employee.LevelChange{}, errDiskFull
+}
+
+func (failingAudit) List(int) ([]employee.LevelChange, error) {
+	return nil, nil
+}
+
+func TestLevelChangeIsUndoneWhenItCannotBeRecorded(t *testing.T) {
+	s := employee.NewService(store.NewMemory(), failingAudit{})
+	e, err := s.Create(newEmployee("Asha", types.Fresher, "Pune", "Maharashtra"))
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if _, err := s.Promote(e.ID, "first year"); !errors.Is(err, errDiskFull) {
+		t.Errorf("Promote err = %v, want the error of the audit log", err)
+	}
+	if got, _ := s.Get(e.ID); got.Level != types.Fresher {
+		t.Errorf("level %v after a promotion that was not recorded, want %v", got.Level, types.Fresher)
+	}
+}
+
+func TestUpdateCannotChangeTheLevel(t *testing.T) {
+	s := newService(t, newEmployee("Asha", types.Fresher, "Pune", "Maharashtra"))
+	e, _ := s.Get(1)
+
+	e.Name, e.Level = "Asha Rao", types.Senior
+	if err := s.Update(e); !errors.Is(err, employee.ErrLevelChange) {
+		t.Errorf("Update to %v err = %v, want ErrLevelChange", e.Level, err)
+	}
+	if got, _ := s.Get(1); got.Name != "Asha" || got.Level != types.Fresher {
+		t.Errorf("employee %+v after a refused Update, want it unchanged", got)
+	}
+
+	e.Level = types.Fresher
+	if err := s.Update(e); err != nil {
+		t.Fatal(err)
+	}
+	if got, _ := s.Get(1); got.Name != "Asha Rao" {
+		t.Errorf("name %q after Update, want Asha Rao", got.Name)
+	}
+}
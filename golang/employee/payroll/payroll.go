@@ -0,0 +1,189 @@
+// Package payroll computes what the employees are paid: the gross salary of a month, the salary band of every level
+// and the annual raise
+// Salaries are money.Money from the calculator, every sum, split and percentage is checked, so an overflow or two
+// currencies in one payroll is an error instead of a wrong payslip
+package payroll
+
+import (
+	"errors"
+	"fmt"
+	"time"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+	"github.com/shashank-priyadarshi/training/calculator/money"
+	"github.com/shashank-priyadarshi/training/types"
+)
+
+var (
+	// ErrNoBand is returned for a level without a salary band
+	ErrNoBand = errors.New("no salary band for the level")
+	// ErrOutOfBand is returned when a salary is below or above the band of the level
+	ErrOutOfBand = errors.New("salary out of band")
+)
+
+// Band is the range of yearly salaries of a level, Min and Max included
+type Band struct {
+	Min money.Money
+	Max money.Money
+}
+
+// Contains reports whether the salary is within the band, it fails when the currencies differ
+func (b Band) Contains(salary money.Money) (bool, error) {
+	low, err := salary.Compare(b.Min)
+	if err != nil {
+		return false, err
+	}
+	high, err := salary.Compare(b.Max)
+	if err != nil {
+		return false, err
+	}
+	return low >= 0 && high <= 0, nil
+}
+
+// Bands are the salary bands of the levels
+type Bands map[types.Level]Band
+
+// DefaultBands returns bands in INR, the bands of neighbouring levels overlap so a promotion does not always need a raise
+func DefaultBands() Bands {
+	return Bands{
+		types.Fresher:   inr(300_000, 600_000),
+		types.Associate: inr(500_000, 900_000),
+		types.Senior:    inr(800_000, 1_500_000),
+		types.Lead:      inr(1_400_000, 2_500_000),
+		types.Manager:   inr(2_000_000, 3_500_000),
+		types.Architect: inr(3_000_000, 5_000_000),
+		types.VP:        inr(4_500_000, 9_000_000),
+		types.CEO:       inr(8_000_000, 30_000_000),
+	}
+}
+
+// inr returns the band from low to high rupees
+func inr(low, high int64) Band {
+	// INR is a known currency and the amounts are far from overflowing, FromMajor cannot fail here
+	lo, _ := money.FromMajor(low, money.INR)
+	hi, _ := money.FromMajor(high, money.INR)
+	return Band{Min: lo, Max: hi}
+}
+
+// Band returns the band of the level, or ErrNoBand
+func (b Bands) Band(level types.Level) (Band, error) {
+	band, ok := b[level]
+	if !ok {
+		return Band{}, fmt.Errorf("%w %v", ErrNoBand, level)
+	}
+	return band, nil
+}
+
+// Check returns ErrOutOfBand when the salary of the employee is not within the band of their level
+func (b Bands) Check(e types.Employee) error {
+	band, err := b.Band(e.Level)
+	if err != nil {
+		return err
+	}
+	ok, err := band.Contains(e.Salary)
+	if err != nil {
+		return err
+	}
+	if !ok {
+		return fmt.Errorf("%w: %v is not between %v and %v for %v", ErrOutOfBand, e.Salary, band.Min, band.Max, e.Level)
+	}
+	return nil
+}
+
+// AnnualRaise returns the yearly salary of the employee after a raise of basisPoints, kept within the band of their
+// level: an employee at the top of the band needs a promotion to earn more, and a pay cut, a negative basisPoints,
+// stops at the bottom of the band
+// The band never moves a salary the wrong way, an employee already above the band keeps their salary after a raise,
+// and one below it keeps theirs after a pay cut
+func (b Bands) AnnualRaise(e types.Employee, basisPoints int64) (money.Money, error) {
+	band, err := b.Band(e.Level)
+	if err != nil {
+		return money.Money{}, err
+	}
+	raised, err := Raise(e.Salary, basisPoints)
+	if err != nil {
+		return money.Money{}, err
+	}
+
+	high, err := e.Salary.Compare(band.Max)
+	if err != nil {
+		return money.Money{}, err
+	}
+	low, err := e.Salary.Compare(band.Min)
+	if err != nil {
+		return money.Money{}, err
+	}
+	top, bottom := band.Max, band.Min
+	if high > 0 {
+		top = e.Salary
+	}
+	if low < 0 {
+		bottom = e.Salary
+	}
+
+	above, err := raised.Compare(top)
+	if err != nil {
+		return money.Money{}, err
+	}
+	below, err := raised.Compare(bottom)
+	if err != nil {
+		return money.Money{}, err
+	}
+	switch {
+	case above > 0:
+		return top, nil
+	case below < 0:
+		return bottom, nil
+	}
+	return raised, nil
+}
+
+// Raise returns the salary increased by basisPoints hundredths of a percent, 750 is a raise of 7.5%
+// A negative raise is a pay cut
+func Raise(salary money.Money, basisPoints int64) (money.Money, error) {
+	increase, err := salary.Rate(basisPoints)
+	if err != nil {
+		return money.Money{}, err
+	}
+	return salary.Add(increase)
+}
+
+// MonthlyGross returns the gross salary of the employee for the month, a twelfth of the yearly salary
+// A yearly salary that does not divide by 12 leaves a few paise over, they are paid in the first months, so the
+// twelve months add up to the yearly salary exactly
+func MonthlyGross(e types.Employee, month time.Month) (money.Money, error) {
+	if month < time.January || month > time.December {
+		return money.Money{}, fmt.Errorf("%w: month %d", calculator.ErrInvalidOperand, int(month))
+	}
+	months, err := e.Salary.Split(12)
+	if err != nil {
+		return money.Money{}, err
+	}
+	return months[month-1], nil
+}
+
+// Payslip is the gross pay of an employee for a month
+type Payslip struct {
+	EmployeeID int
+	Name       string
+	Month      time.Month
+	Gross      money.Money
+}
+
+// Run returns the payslips of the employees for the month and what they add up to
+// All salaries must be in the same currency, the total of two currencies is an error
+func Run(employees []types.Employee, month time.Month) ([]Payslip, money.Money, error) {
+	payslips := make([]Payslip, 0, len(employees))
+	var total money.Money // The zero value takes the currency of the first salary added to it
+	for _, e := range employees {
+		gross, err := MonthlyGross(e, month)
+		if err != nil {
+			return nil, money.Money{}, fmt.Errorf("%s (%d): %w", e.Name, e.ID, err)
+		}
+		if total, err = total.Add(gross); err != nil {
+			return nil, money.Money{}, fmt.Errorf("%s (%d): %w", e.Name, e.ID, err)
+		}
+		payslips = append(payslips, Payslip{EmployeeID: e.ID, Name: e.Name, Month: month, Gross: gross})
+	}
+	return payslips, total, nil
+}
@@ -0,0 +1,139 @@
+package payroll
+
+import (
+	"errors"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/shashank-priyadarshi/training/calculator"
+	"github.com/shashank-priyadarshi/training/calculator/money"
+	"github.com/shashank-priyadarshi/training/types"
+)
+
+// rupees returns whole rupees, and fails the test on an error
+func rupees(t *testing.T, major int64) money.Money {
+	t.Helper()
+	m, err := money.FromMajor(major, money.INR)
+	if err != nil {
+		t.Fatal(err)
+	}
+	return m
+}
+
+func TestCheck(t *testing.T) {
+	bands := DefaultBands()
+	// The Associate band is INR 500000 to 900000, both included
+	for _, tt := range []struct {
+		salary int64
+		want   error
+	}{
+		{500_000, nil},
+		{900_000, nil},
+		{700_000, nil},
+		{499_999, ErrOutOfBand},
+		{900_001, ErrOutOfBand},
+	} {
+		e := types.Employee{Name: "Asha", Level: types.Associate, Salary: rupees(t, tt.salary)}
+		if err := bands.Check(e); !errors.Is(err, tt.want) {
+			t.Errorf("Check of INR %d err = %v, want %v", tt.salary, err, tt.want)
+		}
+	}
+
+	if err := bands.Check(types.Employee{Level: types.Level(42), Salary: rupees(t, 1)}); !errors.Is(err, ErrNoBand) {
+		t.Errorf("Check of an unknown level err = %v, want ErrNoBand", err)
+	}
+	usd, _ := money.FromMajor(600_000, money.USD)
+	if err := bands.Check(types.Employee{Level: types.Associate, Salary: usd}); !errors.Is(err, money.ErrCurrency) {
+		t.Errorf("Check of a salary in USD err = %v, want ErrCurrency", err)
+	}
+}
+
+func TestAnnualRaise(t *testing.T) {
+	bands := DefaultBands()
+	// The Associate band is INR 500000 to 900000
+	for _, tt := range []struct {
+		name        string
+		salary      int64
+		basisPoints int64
+		want        int64
+	}{
+		{"raise within the band", 600_000, 1_000, 660_000},
+		{"raise capped at the top", 850_000, 1_000, 900_000},
+		{"above the band keeps the salary", 950_000, 1_000, 950_000},
+		{"pay cut within the band", 700_000, -1_000, 630_000},
+		{"pay cut stops at the bottom", 520_000, -1_000, 500_000},
+		{"below the band keeps the salary after a pay cut", 450_000, -1_000, 450_000},
+		{"below the band can be raised", 450_000, 1_000, 495_000},
+		{"above the band can be cut", 950_000, -1_000, 855_000},
+		{"no raise", 600_000, 0, 600_000},
+	} {
+		e := types.Employee{Name: "Asha", Level: types.Associate, Salary: rupees(t, tt.salary)}
+		got, err := bands.AnnualRaise(e, tt.basisPoints)
+		if err != nil || got != rupees(t, tt.want) {
+			t.Errorf("%s: AnnualRaise(INR %d, %d) = %v, %v, want INR %d", tt.name, tt.salary, tt.basisPoints, got, err, tt.want)
+		}
+	}
+
+	if _, err := (Bands{}).AnnualRaise(types.Employee{Level: types.Associate, Salary: rupees(t, 1)}, 100); !errors.Is(err, ErrNoBand) {
+		t.Errorf("AnnualRaise without bands err = %v, want ErrNoBand", err)
+	}
+	usd, _ := money.FromMajor(600_000, money.USD)
+	if _, err := bands.AnnualRaise(types.Employee{Level: types.Associate, Salary: usd}, 100); !errors.Is(err, money.ErrCurrency) {
+		t.Errorf("AnnualRaise of a salary in USD err = %v, want ErrCurrency", err)
+	}
+}
+
+func TestMonthlyGross(t *testing.T) {
+	// INR 100.00 is 10000 paise, 833 a month and 4 paise over, paid in the first four months
+	salary, _ := money.New(10_000, money.INR)
+	e := types.Employee{Name: "Asha", Salary: salary}
+
+	var total money.Money
+	for month := time.January; month <= time.December; month++ {
+		gross, err := MonthlyGross(e, month)
+		if err != nil {
+			t.Fatal(err)
+		}
+		want := int64(833)
+		if month <= time.April {
+			want = 834
+		}
+		if gross.Minor() != want {
+			t.Errorf("MonthlyGross in %v = %d paise, want %d", month, gross.Minor(), want)
+		}
+		total, _ = total.Add(gross)
+	}
+	if total != salary {
+		t.Errorf("the months add up to %v, want %v", total, salary)
+	}
+
+	for _, month := range []time.Month{0, 13} {
+		if _, err := MonthlyGross(e, month); !errors.Is(err, calculator.ErrInvalidOperand) {
+			t.Errorf("MonthlyGross in month %d err = %v, want ErrInvalidOperand", int(month), err)
+		}
+	}
+}
+
+func TestRun(t *testing.T) {
+	employees := []types.Employee{
+		{ID: 1, Name: "Asha", Salary: rupees(t, 600_000)},
+		{ID: 2, Name: "Ravi", Salary: rupees(t, 1_200_000)},
+	}
+	payslips, total, err := Run(employees, time.March)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(payslips) != 2 || payslips[0].Gross != rupees(t, 50_000) || payslips[1].Gross != rupees(t, 100_000) {
+		t.Errorf("payslips %+v, want INR 50000 for Asha and INR 100000 for Ravi", payslips)
+	}
+	if total != rupees(t, 150_000) {
+		t.Errorf("total %v, want INR 150000.00", total)
+	}
+
+	usd, _ := money.FromMajor(60_000, money.USD)
+	employees = append(employees, types.Employee{ID: 3, Name: "John", Salary: usd})
+	if _, _, err := Run(employees, time.March); !errors.Is(err, money.ErrCurrency) || !strings.Contains(err.Error(), "John (3)") {
+		t.Errorf("Run with a salary in USD err = %v, want ErrCurrency for John", err)
+	}
+}
@@ -1,32 +1,124 @@
 package main
 
 import (
-	// fmt is a standard package provided by Golang
-	"fmt" // Package fmt implements formatting operations on the console like printing, reading input, etc
+	// These are standard packages provided by Golang
+	"context"   // Package context carries cancellation signals, e.g to stop a batch when Ctrl+C is pressed
+	"flag"      // Package flag parses command line flags, like -demo
+	"fmt"       // Package fmt implements formatting operations on the console like printing, reading input, etc
+	"os"        // Package os gives access to the standard input and output of the program
+	"os/signal" // Package signal turns signals sent to the program, like Ctrl+C, into a cancelled context
+	"strings"   // Package strings implements functions to work with strings, like strings.Join
 
 	// These are custom packages defined by us
-	"github.com/shashank-priyadarshi/training/calculator/add"      // Importing add package from calculator
-	"github.com/shashank-priyadarshi/training/calculator/divide"   // Importing divide package from calculator
-	"github.com/shashank-priyadarshi/training/calculator/multiply" // Importing multiply package from calculator
-	"github.com/shashank-priyadarshi/training/calculator/subtract" // Importing subtract package from calculator
+	"github.com/shashank-priyadarshi/training/calculator/adapter/cli"     // Importing the command line adapter of the calculator
+	"github.com/shashank-priyadarshi/training/calculator/adapter/history" // Importing the storage adapters of the calculator history
+	"github.com/shashank-priyadarshi/training/calculator/adapter/httpapi" // Importing the HTTP adapter of the calculator
+	"github.com/shashank-priyadarshi/training/calculator/app"             // Importing the core of the calculator, the adapters call it
+	"github.com/shashank-priyadarshi/training/calculator/expr"            // Importing the expression parser and evaluator from calculator
 )
 
 // Calculator
 // Add, Subtract, Multiply, Divide
+// go run . starts the interactive calculator, go run . -demo evaluates a few example expressions
+// go run . sqrt 2 calls a function by name with the remaining arguments, in float mode unless -mode is given
+// -mode selects the type of the numbers, e.g go run . -demo -mode bigint
+// go run . -http :8080 serves the calculator over HTTP, see package httpapi
+// go run . -batch exprs.txt -format csv evaluates one expression per line of a file, -batch - reads them from the standard input
+// -workers evaluates the lines of a batch concurrently, and -timeout limits the time of each line, e.g -workers 8 -timeout 1s
+// Evaluations are recorded in memory, or in a file with -history calc.jsonl, go run . -history calc.jsonl history lists them
+//
+// main only wires the application together: the core (app) is created once and handed to the adapter selected by the flags
+// This is the hexagonal architecture, see package app
 func main() {
-	a := add.Add(1, 2)
-	fmt.Println("Adding: ", a) // Writing to the console
+	runDemo := flag.Bool("demo", false, "evaluate a few example expressions instead of starting the interactive calculator")
+	mode := flag.String("mode", "", "type of the numbers, one of "+strings.Join(expr.Modes(), ", "))
+	addr := flag.String("http", "", "serve the calculator over HTTP on this address, e.g :8080")
+	historyFile := flag.String("history", "", "record the evaluations in this JSON lines file instead of in memory")
+	batchFile := flag.String("batch", "", "evaluate one expression per line of this file, - for the standard input")
+	format := flag.String("format", "plain", "output format of -batch, one of "+strings.Join(cli.Formats(), ", "))
+	workers := flag.Int("workers", 1, "number of expressions of -batch evaluated at the same time")
+	timeout := flag.Duration("timeout", 0, "time limit of each expression of -batch, e.g 500ms, no limit when 0")
+	flag.Parse()
 
-	a = subtract.Subtract(1, 2)
-	fmt.Println("Subtracting: ", a)
+	var store app.HistoryRepository = history.NewMemory()
+	if *historyFile != "" {
+		file, err := history.OpenFile(*historyFile)
+		if err != nil {
+			fmt.Fprintln(os.Stderr, err)
+			os.Exit(1)
+		}
+		store = file
+	}
+	core := app.NewService(store)
 
-	a = multiply.Multiply(1, 2)
-	fmt.Println("Multiplying: ", a)
+	if *addr != "" {
+		fmt.Println("Calculator listening on", *addr)
+		if err := httpapi.NewServer(*addr, core).ListenAndServe(); err != nil {
+			fmt.Fprintln(os.Stderr, err)
+			os.Exit(1)
+		}
+		return
+	}
 
-	a = divide.Divide(1, 2) // It will print 0, not 0.5, Data types are important
-	fmt.Println("Dividing: ", a)
+	terminal := cli.New(core, os.Stdin, os.Stdout, os.Stderr)
+	if *batchFile != "" {
+		os.Exit(batch(terminal, *batchFile, *mode, *format, cli.BatchOptions{Workers: *workers, Timeout: *timeout}))
+	}
+	if *runDemo {
+		terminal.Demo(*mode)
+		return
+	}
 
-	// It will cause runtime error, not compile time error
-	a = divide.Divide(1, 0) // It will panic, because we are dividing by 0
-	fmt.Println("Dividing: ", a)
+	if flag.Arg(0) == "history" {
+		if err := terminal.History(flag.Args()[1:]); err != nil {
+			fmt.Fprintln(os.Stderr, err)
+			os.Exit(1)
+		}
+		return
+	}
+
+	if flag.NArg() > 0 {
+		if *mode == "" {
+			*mode = "float"
+		}
+		if err := terminal.Call(*mode, flag.Arg(0), flag.Args()[1:]); err != nil {
+			fmt.Fprintln(os.Stderr, err)
+			os.Exit(1)
+		}
+		return
+	}
+
+	if err := terminal.Interactive(*mode); err != nil {
+		fmt.Fprintln(os.Stderr, err)
+		os.Exit(1)
+	}
+}
+
+// batch evaluates the expressions of the file, and returns the exit code of the program for shell scripts:
+// 0 when every expression was evaluated, 1 when some failed, and 2 when the file could not be read or Ctrl+C was pressed
+func batch(terminal *cli.CLI, path, mode, format string, opts cli.BatchOptions) int {
+	in := os.Stdin
+	if path != "-" {
+		file, err := os.Open(path)
+		if err != nil {
+			fmt.Fprintln(os.Stderr, err)
+			return 2
+		}
+		defer file.Close()
+		in = file
+	}
+
+	ctx, stop := signal.NotifyContext(context.Background(), os.Interrupt)
+	defer stop()
+
+	summary, err := terminal.Batch(ctx, in, mode, format, opts)
+	if err != nil {
+		fmt.Fprintln(os.Stderr, err)
+		return 2
+	}
+	if summary.Failed > 0 {
+		fmt.Fprintf(os.Stderr, "%d of %d expressions failed\n", summary.Failed, summary.Evaluated)
+		return 1
+	}
+	return 0
 }
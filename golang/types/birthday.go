@@ -0,0 +1,88 @@
+package types
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"time"
+)
+
+// ErrNoDateOfBirth is returned by NotifyBirthday for an employee without a date of birth
+var ErrNoDateOfBirth = errors.New("employee has no date of birth")
+
+// Clock tells the time, NotifyBirthday gets it as an argument instead of calling time.Now and time.After itself
+// A fake clock can then jump to the next birthday at once, so NotifyBirthday can be tested without waiting a year
+type Clock interface {
+	Now() time.Time
+	// After returns a channel that receives the time once d has passed
+	// The wait is stopped when ctx is done, a timer of a year is not left running after NotifyBirthday returned
+	After(ctx context.Context, d time.Duration) <-chan time.Time
+}
+
+// Notifier sends a message to an employee, e.g by printing it, writing it to a file or calling a webhook
+// Any type with this method is a Notifier, there is no implements keyword in Go, see package employee/birthday
+type Notifier interface {
+	Notify(ctx context.Context, e Employee, message string) error
+}
+
+// NextBirthday returns the start of the first birthday of the employee that is not over at t, in the location of t
+// A birthday lasts the whole day: at 10:00 on the birthday, NextBirthday returns midnight of that day, which is
+// before t, and not the birthday of the next year
+// Someone born on February 29 celebrates on February 28 in years that are not leap years
+// It returns the zero time when the employee has no date of birth
+func (e Employee) NextBirthday(t time.Time) time.Time {
+	if e.DateOfBirth.IsZero() {
+		return time.Time{}
+	}
+	next := e.birthdayIn(t.Year(), t.Location())
+	if !t.Before(next.AddDate(0, 0, 1)) { // The birthday of this year is over
+		next = e.birthdayIn(t.Year()+1, t.Location())
+	}
+	return next
+}
+
+// birthdayIn returns the start of the birthday of the employee in the year
+func (e Employee) birthdayIn(year int, loc *time.Location) time.Time {
+	month, day := e.DateOfBirth.Month(), e.DateOfBirth.Day()
+	if month == time.February && day == 29 && !isLeap(year) {
+		day = 28 // time.Date would turn February 29 into March 1
+	}
+	return time.Date(year, month, day, 0, 0, 0, 0, loc)
+}
+
+// isLeap reports whether the year has a February 29: every fourth year, except every hundredth, except every 400th
+func isLeap(year int) bool {
+	return year%4 == 0 && (year%100 != 0 || year%400 == 0)
+}
+
+// NotifyBirthday sends a "Happy Birthday" message on every birthday of the employee, until ctx is cancelled
+// It starts a timer for the next birthday, and when the timer stops it sends the message and starts the timer
+// again for the birthday of the next year
+// Started during a birthday, e.g at 10:00, it sends the message of that birthday at once
+// It returns the error of ctx when it is cancelled, or the error of the notifier when a message could not be sent
+func (e Employee) NotifyBirthday(ctx context.Context, clock Clock, notifier Notifier) error {
+	if e.DateOfBirth.IsZero() {
+		return fmt.Errorf("%w: %s", ErrNoDateOfBirth, e.Name)
+	}
+
+	next := e.NextBirthday(clock.Now())
+	for {
+		if err := ctx.Err(); err != nil {
+			return err
+		}
+		// The time is checked again after every wait, the clock can say it is still before next, e.g when it was changed
+		for now := clock.Now(); now.Before(next); now = clock.Now() {
+			select {
+			case <-ctx.Done():
+				return ctx.Err()
+			case <-clock.After(ctx, next.Sub(now)):
+			}
+		}
+
+		if err := notifier.Notify(ctx, e, fmt.Sprintf("Happy Birthday, %s!", e.Name)); err != nil {
+			return err
+		}
+		// The next birthday is searched from the day after this one, or it would be this one again
+		next = e.NextBirthday(next.AddDate(0, 0, 1))
+	}
+}
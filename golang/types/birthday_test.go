@@ -0,0 +1,45 @@
+package types
+
+import (
+	"testing"
+	"time"
+)
+
+func TestNextBirthday(t *testing.T) {
+	may17 := Employee{Name: "Shashank", DateOfBirth: date(1990, time.May, 17)}
+	feb29 := Employee{Name: "Leap", DateOfBirth: date(2000, time.February, 29)}
+
+	for _, tt := range []struct {
+		name string
+		e    Employee
+		t    time.Time
+		want time.Time
+	}{
+		{"later this year", may17, date(2024, time.January, 1), date(2024, time.May, 17)},
+		{"at the start of the birthday", may17, date(2024, time.May, 17), date(2024, time.May, 17)},
+		// The birthday started at midnight, it is not over at 10:00
+		{"earlier today", may17, date(2024, time.May, 17).Add(10 * time.Hour), date(2024, time.May, 17)},
+		{"last moment of the birthday", may17, date(2024, time.May, 18).Add(-time.Nanosecond), date(2024, time.May, 17)},
+		{"the day after", may17, date(2024, time.May, 18), date(2025, time.May, 17)},
+		{"February 29 in a leap year", feb29, date(2024, time.January, 1), date(2024, time.February, 29)},
+		{"February 28 in other years", feb29, date(2025, time.January, 1), date(2025, time.February, 28)},
+		{"after February 28", feb29, date(2025, time.March, 1), date(2026, time.February, 28)},
+		// 2100 is not a leap year, it divides by 100 but not by 400
+		{"2100", feb29, date(2100, time.January, 1), date(2100, time.February, 28)},
+		{"no date of birth", Employee{Name: "Nobody"}, date(2024, time.January, 1), time.Time{}},
+	} {
+		if got := tt.e.NextBirthday(tt.t); !got.Equal(tt.want) {
+			t.Errorf("%s: NextBirthday(%v) = %v, want %v", tt.name, tt.t, got, tt.want)
+		}
+	}
+}
+
+func TestAgeOnFebruary28(t *testing.T) {
+	feb29 := Employee{Name: "Leap", DateOfBirth: date(2000, time.February, 29)}
+	if got := feb29.Age(date(2025, time.February, 27)); got != 24 {
+		t.Errorf("Age on February 27 = %d, want 24", got)
+	}
+	if got := feb29.Age(date(2025, time.February, 28)); got != 25 {
+		t.Errorf("Age on February 28 = %d, want 25, a year older on February 28 when there is no February 29", got)
+	}
+}
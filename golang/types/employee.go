@@ -0,0 +1,99 @@
+package types
+
+import (
+	"errors"
+	"fmt"
+	"time"
+
+	"github.com/shashank-priyadarshi/training/calculator/money"
+)
+
+// ErrAddressDate is returned when a new address would apply before the current one, the history must stay in order
+var ErrAddressDate = errors.New("address date is before the current address")
+
+type Employee struct {
+	// A struct can have any number of properties
+	// Type of these properties can be any scalar, vector, custom or composite data type
+	// Properties of a struct are called fields
+	// Fields of a struct can be accessed using the dot operator
+
+	// For this employee class, if we create an instance: object
+	// For that object, the level, salary and address are subject to change, and the age changes every year
+	// This is called behaviour of the employee class
+	// Behaviour of a class is defined by methods
+	ID           int // Assigned by the employee registry, counting from 1, 0 until the employee is registered
+	Name         string
+	DateOfBirth  time.Time // Only the date is used, see NextBirthday
+	Level        Level
+	Salary       money.Money   // Yearly gross salary, an amount in a currency instead of a string like "50k"
+	Address      Address       // Current address
+	AddressSince time.Time     // Date from which Address is the current address
+	Permanent    Address       // Permanent address, e.g the home town, it does not change when the employee moves
+	Previous     []PastAddress // Addresses before the current one, the oldest first
+}
+
+// Age returns the age of the employee in whole years on the date, it is derived from DateOfBirth so it is never out of date
+// Like NextBirthday, someone born on February 29 gets a year older on February 28 in years that are not leap years
+// It returns 0 when the employee has no date of birth
+func (e Employee) Age(on time.Time) int {
+	if e.DateOfBirth.IsZero() {
+		return 0
+	}
+	age := on.Year() - e.DateOfBirth.Year()
+	if on.Before(e.birthdayIn(on.Year(), on.Location())) {
+		age-- // No birthday yet this year
+	}
+	return max(age, 0)
+}
+
+// UpdateAddress makes address the current address from the date from, the current address moves to Previous
+// It has a pointer receiver: e points to the employee the method is called on, so the change is kept
+// With a value receiver, e would be a copy of the employee, and the new address would be lost when the method returns
+func (e *Employee) UpdateAddress(address Address, from time.Time) error {
+	if from.Before(e.AddressSince) {
+		return fmt.Errorf("%w: %s is before %s", ErrAddressDate, from.Format(time.DateOnly), e.AddressSince.Format(time.DateOnly))
+	}
+	if e.Address != (Address{}) {
+		e.Previous = append(e.Previous, PastAddress{Address: e.Address, From: e.AddressSince, To: from})
+	}
+	e.Address, e.AddressSince = address, from
+	return nil
+}
+
+// WithAddress returns a copy of e with a new current address, e itself does not change
+// A value receiver is the right choice when a method should not change the value it is called on
+// Previous is a slice, so it is copied before the append: the copy and e would share the array under it otherwise
+func (e Employee) WithAddress(address Address, from time.Time) (Employee, error) {
+	e.Previous = append([]PastAddress(nil), e.Previous...)
+	err := e.UpdateAddress(address, from) // e is addressable, Go calls (&e).UpdateAddress on the copy
+	return e, err
+}
+
+// AddressOn returns the address of the employee on a date, it is false when the employee had no known address yet
+func (e Employee) AddressOn(date time.Time) (Address, bool) {
+	if e.Address != (Address{}) && !date.Before(e.AddressSince) {
+		return e.Address, true
+	}
+	for _, past := range e.Previous {
+		if !date.Before(past.From) && date.Before(past.To) {
+			return past.Address, true
+		}
+	}
+	return Address{}, false
+}
+
+// The tags after the fields are the names of the fields in JSON, e.g in the file of the employee registry
+type Address struct {
+	Street  string `json:"street,omitempty"`
+	City    string `json:"city,omitempty"`
+	State   string `json:"state,omitempty"`
+	Pincode string `json:"pincode,omitempty"`
+}
+
+// PastAddress is an address the employee lived at from the date From until the day before To
+// The fields of the embedded Address are written next to From and To in JSON
+type PastAddress struct {
+	Address
+	From time.Time `json:"from"`
+	To   time.Time `json:"to"`
+}
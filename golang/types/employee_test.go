@@ -0,0 +1,130 @@
+package types
+
+import (
+	"errors"
+	"testing"
+	"time"
+)
+
+var (
+	bengaluru = Address{Street: "MG Road", City: "Bengaluru", State: "Karnataka", Pincode: "560001"}
+	hyderabad = Address{Street: "Banjara Hills", City: "Hyderabad", State: "Telangana", Pincode: "500034"}
+	pune      = Address{Street: "FC Road", City: "Pune", State: "Maharashtra", Pincode: "411004"}
+)
+
+func date(year int, month time.Month, day int) time.Time {
+	return time.Date(year, month, day, 0, 0, 0, 0, time.UTC)
+}
+
+// relocateCopy and relocate are the two ways of passing an employee to a function, like the two kinds of receivers
+func relocateCopy(e Employee, a Address, from time.Time) {
+	_ = e.UpdateAddress(a, from) // Updates the copy that relocateCopy received
+}
+
+func relocate(e *Employee, a Address, from time.Time) {
+	_ = e.UpdateAddress(a, from)
+}
+
+func TestUpdateAddressChangesTheEmployee(t *testing.T) {
+	e := Employee{Name: "Shashank"}
+	if err := e.UpdateAddress(bengaluru, date(2020, 1, 1)); err != nil {
+		t.Fatal(err)
+	}
+	if e.Address != bengaluru {
+		t.Errorf("Address = %v, want %v", e.Address, bengaluru)
+	}
+	if len(e.Previous) != 0 {
+		t.Errorf("Previous = %v, want no previous address for the first address", e.Previous)
+	}
+}
+
+func TestPassingByValueDoesNotChangeTheEmployee(t *testing.T) {
+	e := Employee{Name: "Shashank"}
+	_ = e.UpdateAddress(bengaluru, date(2020, 1, 1))
+
+	relocateCopy(e, hyderabad, date(2023, 6, 1))
+	if e.Address != bengaluru {
+		t.Errorf("after relocateCopy Address = %v, want %v, the function changed a copy", e.Address, bengaluru)
+	}
+
+	relocate(&e, hyderabad, date(2023, 6, 1))
+	if e.Address != hyderabad {
+		t.Errorf("after relocate Address = %v, want %v, the function changed e through the pointer", e.Address, hyderabad)
+	}
+}
+
+func TestWithAddressReturnsAChangedCopy(t *testing.T) {
+	e := Employee{Name: "Shashank"}
+	_ = e.UpdateAddress(bengaluru, date(2020, 1, 1))
+	_ = e.UpdateAddress(hyderabad, date(2023, 6, 1))
+
+	moved, err := e.WithAddress(pune, date(2024, 3, 1))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if moved.Address != pune || len(moved.Previous) != 2 {
+		t.Errorf("moved = %v with %d previous addresses, want %v with 2", moved.Address, len(moved.Previous), pune)
+	}
+	if e.Address != hyderabad || len(e.Previous) != 1 {
+		t.Errorf("e = %v with %d previous addresses, want %v with 1, the value receiver must not change e", e.Address, len(e.Previous), hyderabad)
+	}
+
+	// The copy has its own Previous, a move of e cannot overwrite the history of moved
+	_ = e.UpdateAddress(bengaluru, date(2024, 5, 1))
+	if got := moved.Previous[1].Address; got != hyderabad {
+		t.Errorf("moved.Previous[1] = %v, want %v", got, hyderabad)
+	}
+}
+
+func TestAddressHistory(t *testing.T) {
+	e := Employee{Name: "Shashank", Permanent: Address{City: "Patna", State: "Bihar"}}
+	_ = e.UpdateAddress(bengaluru, date(2020, 1, 1))
+	_ = e.UpdateAddress(hyderabad, date(2023, 6, 1))
+	_ = e.UpdateAddress(pune, date(2024, 3, 1))
+
+	want := []PastAddress{
+		{Address: bengaluru, From: date(2020, 1, 1), To: date(2023, 6, 1)},
+		{Address: hyderabad, From: date(2023, 6, 1), To: date(2024, 3, 1)},
+	}
+	if len(e.Previous) != len(want) {
+		t.Fatalf("Previous = %v, want %v", e.Previous, want)
+	}
+	for i := range want {
+		if e.Previous[i] != want[i] {
+			t.Errorf("Previous[%d] = %v, want %v", i, e.Previous[i], want[i])
+		}
+	}
+	if e.Permanent.City != "Patna" {
+		t.Errorf("Permanent = %v, moving must not change the permanent address", e.Permanent)
+	}
+
+	for _, tt := range []struct {
+		on   time.Time
+		want Address
+		ok   bool
+	}{
+		{date(2019, 12, 31), Address{}, false},
+		{date(2020, 1, 1), bengaluru, true},
+		{date(2023, 5, 31), bengaluru, true},
+		{date(2023, 6, 1), hyderabad, true},
+		{date(2025, 1, 1), pune, true},
+	} {
+		got, ok := e.AddressOn(tt.on)
+		if got != tt.want || ok != tt.ok {
+			t.Errorf("AddressOn(%s) = %v, %v, want %v, %v", tt.on.Format(time.DateOnly), got, ok, tt.want, tt.ok)
+		}
+	}
+}
+
+func TestUpdateAddressRejectsAnEarlierDate(t *testing.T) {
+	e := Employee{Name: "Shashank"}
+	_ = e.UpdateAddress(hyderabad, date(2023, 6, 1))
+
+	err := e.UpdateAddress(bengaluru, date(2020, 1, 1))
+	if !errors.Is(err, ErrAddressDate) {
+		t.Fatalf("err = %v, want ErrAddressDate", err)
+	}
+	if e.Address != hyderabad || len(e.Previous) != 0 {
+		t.Errorf("e = %v with %d previous addresses, a rejected address must change nothing", e.Address, len(e.Previous))
+	}
+}
@@ -0,0 +1,70 @@
+// Package types walks through the data types of Go, one lesson per function: integers, float, strings, pointers,
+// arrays, slices, maps, custom and structs
+// Run runs a lesson by name, see cmd/lessons
+package types
+
+import (
+	"errors"
+	"fmt"
+)
+
+// ErrUnknownLesson is returned by Run for a name that is not in Lessons
+var ErrUnknownLesson = errors.New("unknown lesson")
+
+// lesson is a lesson with the name it is run with
+type lesson struct {
+	name string
+	run  func()
+}
+
+// lessons are in the order they are taught, the same order as in types
+var lessons = []lesson{
+	{"integers", integers},
+	{"float", float},
+	{"strings", strings},
+	{"pointers", pointers},
+	{"arrays", arrays},
+	{"slices", slices},
+	{"maps", maps},
+	{"custom", custom},
+	{"structs", structs},
+}
+
+// Lessons returns the names of the lessons, in the order they are taught
+func Lessons() []string {
+	names := make([]string, len(lessons))
+	for i, l := range lessons {
+		names[i] = l.name
+	}
+	return names
+}
+
+// Run runs the lesson with the given name, or every lesson one after the other when the name is all
+func Run(name string) error {
+	if name == "all" {
+		types()
+		return nil
+	}
+	for _, l := range lessons {
+		if l.name == name {
+			l.run()
+			return nil
+		}
+	}
+	return fmt.Errorf("%w %q, available lessons are %v and all", ErrUnknownLesson, name, Lessons())
+}
+
+// expectFailure runs code that is meant to fail while the program runs, like writing to a nil map,
+// and prints how it failed instead of stopping the lesson
+// Code that does not compile, like assigning a string to an int, cannot be run and stays in comments
+//
+// A failure at runtime is a panic, recover stops it, but only when it is called by a deferred function
+func expectFailure(what string, f func()) {
+	defer func() {
+		if r := recover(); r != nil {
+			fmt.Printf("expected failure, %s: %v\n", what, r)
+		}
+	}()
+	f()
+	fmt.Printf("%s was expected to fail, but it did not\n", what)
+}
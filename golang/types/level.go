@@ -0,0 +1,69 @@
+package types
+
+import (
+	"errors"
+	"fmt"
+	gostrings "strings" // strings is the name of the lesson about strings in this package
+)
+
+// ErrUnknownLevel is returned by ParseLevel for a name that is not a level
+var ErrUnknownLevel = errors.New("unknown level")
+
+// Level is an enum: a named integer type with a constant for every value, iota counts them up from 0
+// The order matters, a higher level is a promotion
+type Level int8
+
+const (
+	Fresher Level = iota
+	Associate
+	Senior
+	Lead
+	Manager
+	Architect
+	VP
+	CEO
+)
+
+// levelNames are the names of the levels, in the order of the constants
+var levelNames = [...]string{"Fresher", "Associate", "Senior", "Lead", "Manager", "Architect", "VP", "CEO"}
+
+// Valid reports whether l is one of the constants, a Level is an int8 so Level(100) compiles too
+func (l Level) Valid() bool {
+	return l >= Fresher && l <= CEO
+}
+
+// String returns the name of the level, fmt uses it to print levels: fmt.Println(Senior) prints Senior instead of 2
+func (l Level) String() string {
+	if !l.Valid() {
+		return fmt.Sprintf("Level(%d)", int8(l))
+	}
+	return levelNames[l]
+}
+
+// ParseLevel returns the level with the name, ignoring case: ParseLevel("senior") is Senior
+func ParseLevel(name string) (Level, error) {
+	for i, n := range levelNames {
+		if gostrings.EqualFold(n, name) {
+			return Level(i), nil
+		}
+	}
+	return 0, fmt.Errorf("%w %q, expected one of %s", ErrUnknownLevel, name, gostrings.Join(levelNames[:], ", "))
+}
+
+// MarshalText writes the name of the level, so encoding/json writes "Senior" instead of 2
+func (l Level) MarshalText() ([]byte, error) {
+	if !l.Valid() {
+		return nil, fmt.Errorf("%w %d", ErrUnknownLevel, int8(l))
+	}
+	return []byte(l.String()), nil
+}
+
+// UnmarshalText reads the name of a level, it has a pointer receiver because it changes l
+func (l *Level) UnmarshalText(text []byte) error {
+	level, err := ParseLevel(string(text))
+	if err != nil {
+		return err
+	}
+	*l = level
+	return nil
+}
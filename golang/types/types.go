@@ -1,6 +1,18 @@
 package types
 
-import "fmt"
+import (
+	"fmt"
+	"time"
+
+	"github.com/shashank-priyadarshi/training/calculator/add"
+	"github.com/shashank-priyadarshi/training/calculator/bitwise"
+	"github.com/shashank-priyadarshi/training/calculator/divide"
+	"github.com/shashank-priyadarshi/training/calculator/matrix"
+	"github.com/shashank-priyadarshi/training/calculator/money"
+	"github.com/shashank-priyadarshi/training/calculator/multiply"
+	"github.com/shashank-priyadarshi/training/calculator/rational"
+	"github.com/shashank-priyadarshi/training/calculator/units"
+)
 
 // Statically Typed Languages: Types are static after they have been defined for a variable
 // Dynamically Typed Languages: Types are dynamic, which means types of variables can change within the same scope based no value
@@ -51,6 +63,9 @@ func types() {
 
 	// Custom data types
 	custom()
+
+	// Structs
+	structs()
 }
 
 func integers() {
@@ -80,6 +95,18 @@ func integers() {
 	fmt.Println("value of c: ", c)
 	fmt.Println("value of d: ", d)
 
+	// The calculator works with every size of integer, and the size decides what happens at the edges of the range
+	fmt.Println("int8 127 + 1: ", add.Add[int8](127, 1))         // Prints -128, the value wraps around
+	fmt.Println("uint16 65535 + 1: ", add.Add[uint16](65535, 1)) // Prints 0
+	fmt.Println("int 1 / 2: ", divide.Divide(1, 2))             // Prints 0, integer division drops the fraction
+
+	// Negative integers are stored in two's complement: flip every bit of the positive value and add 1
+	// The leftmost bit is the sign bit, this is why an int8 stops at 127 and goes down to -128
+	fmt.Println("int8 127 in bits: ", bitwise.Pattern[int8](127))   // Prints 01111111, the largest int8
+	fmt.Println("int8 -1 in bits: ", bitwise.Pattern[int8](-1))     // Prints 11111111, every bit is set
+	fmt.Println("int8 -128 in bits: ", bitwise.Pattern[int8](-128)) // Prints 10000000, only the sign bit is set
+	fmt.Println("uint8 255 in bits: ", bitwise.Pattern[uint8](255)) // Prints 11111111 too, uint8 has no sign bit
+
 	// TODO: int(4.3) cannot do this: untyped float constant
 	// TODO: int(float(4.3)) cannot do this: float has no return type
 	w := 4.3
@@ -106,6 +133,9 @@ func float() {
 	fmt.Println("value of x: ", x)
 	fmt.Println("value of y: ", y)
 	fmt.Println("value of z: ", z)
+
+	fmt.Println("float32 1 / 2: ", divide.Divide[float32](1, 2)) // Prints 0.5
+	fmt.Println("float32 1 / 3: ", divide.Divide[float32](1, 3)) // Prints 0.33333334, float32 has fewer digits than float64
 }
 
 func strings() {
@@ -163,6 +193,17 @@ func pointers() {
 	fmt.Println("value of x: ", x) // Prints address where 5 is stored
 
 	fmt.Println("dereferenced value of x: ", *x) // dereferencing // This prints 5, as * is used to declare pointer types and to get value contained at a memory location
+
+	// Go passes everything by value: a function gets a copy of its arguments, and a method a copy of its receiver
+	// A method that changes its receiver needs a pointer receiver, like Employee.UpdateAddress in employee.go
+	// With a value receiver the method would change its own copy, and the change would be lost when it returns
+
+	// A pointer that was declared but never given an address is nil, it points nowhere
+	// Dereferencing it compiles, but stops the program when it runs
+	expectFailure("dereferencing a nil pointer", func() {
+		var p *int
+		fmt.Println(*p)
+	})
 }
 
 func arrays() {
@@ -188,6 +229,12 @@ func arrays() {
 	// arr1[10] = "Priyadarshi" // Invalid code: Indexing starts from 0, so index of 10th item will be = 10 - 1 = 9
 	arr1[10-1] = "Priyadarshi"
 
+	// The compiler only catches constant indices, an index known at runtime is checked when the program runs
+	expectFailure("index 10 of an array of 10 items", func() {
+		i := 10
+		arr1[i] = "Priyadarshi"
+	})
+
 	// {
 	// 	var arr [10]string
 	// 	var arr [11]string
@@ -208,6 +255,21 @@ func arrays() {
 	// This array should also contain integers
 	// Try adding negative values into this array
 	// All values in this array will be limited to 0-255
+
+	// The calculator shows what happens when a uint8 value goes out of 0-255
+	var small [3]uint8
+	_, err := add.Checked[uint8](250, 10)       // Returns an error, 260 does not fit into a uint8
+	small[0] = add.Saturating[uint8](250, 10)   // 255, the value stops at the largest uint8
+	small[1] = add.Wrapping[uint8](250, 10)     // 4, the value wraps around: 260 - 256
+	small[2] = multiply.Wrapping[uint8](16, 16) // 0, 256 wraps around to 0
+	fmt.Println("uint8 array: ", small, err)
+
+	// An array of arrays has a fixed number of rows and columns, like the 2x2 matrices of the calculator
+	rotate := matrix.Mat2{{0, -1}, {1, 0}} // Rotates by 90 degrees
+	copied := rotate                       // Arrays are values: copied is a copy, changing it does not change rotate
+	copied[0][0] = 5
+	inverse, _ := rotate.Inverse()
+	fmt.Println("2x2 array: ", rotate, copied, inverse, rotate.Multiply(inverse)) // rotate times its inverse is [[1 0] [0 1]]
 }
 
 func slices() {
@@ -234,7 +296,17 @@ func slices() {
 	// Length of a slice is the number of items the slice currently holds
 	// Capacity is the number of items the slice can hold
 	// The capacity of a slice can change based on requirement
-	s2[11] = "Shashank" // The same case would be invalid for an array because an array of size 10 will have highest index of 10 -1 = 9
+	// Only the items up to the length can be accessed: s2 has length 0, so even s2[0] is out of range, whatever the capacity is
+	expectFailure("index 11 of a slice of length 0", func() {
+		s2[11] = "Shashank"
+	})
+
+	// append adds items after the last one, the length grows and the capacity grows when it is reached
+	for i := 0; i < 12; i++ {
+		s2 = append(s2, "Shashank")
+	}
+	s2[11] = "Priyadarshi"                                       // Index 11 exists now, the length is 12
+	fmt.Println("length and capacity of s2: ", len(s2), cap(s2)) // The capacity went past 10, a larger array was created
 
 	// Internally, a slice stores data in an array
 	// If type of slice is specified, an array of same type is created, using the capacity of the slice
@@ -246,6 +318,14 @@ func slices() {
 	// Do not initialize the slice
 	// Then, put an item in the list at index greater than the capacity
 
+	// A slice of slices can have any number of rows and columns, chosen at runtime, like matrix.Matrix of the calculator
+	// Every row is a slice of its own, so matrix.New checks that all rows have the same length
+	m, _ := matrix.New([][]float64{{1, 2, 3}, {4, 5, 6}})  // 2 rows, 3 columns
+	product, _ := m.Multiply(m.Transpose())                // 2x3 times 3x2 is 2x2
+	_, err := matrix.New([][]float64{{1, 2}, {3}})         // The second row is too short
+	singular, _ := matrix.New([][]float64{{1, 2}, {2, 4}}) // The second row is twice the first
+	_, errSingular := singular.Inverse()                   // Like dividing by 0, see matrix.ErrSingular
+	fmt.Println("slice of slices: ", product, err, errSingular)
 }
 
 func maps() {
@@ -266,6 +346,12 @@ func maps() {
 
 	fmt.Println(x, y, z)
 
+	// x is declared but not initialized, so it is nil: it can be read like an empty map, but not written to
+	fmt.Println("reading a nil map: ", len(x), x[1])
+	expectFailure("writing to a nil map", func() {
+		x[1] = "Shashank"
+	})
+
 	// In the following list []int{0,1,2,3,4,5,6,7,8,9}: operation list[5] will always return the 6th item that is 5
 	// In the following map map[int]string{1: "Shashank Priyadarshi", 2: "Shashank P", 3: "P Shashank", 4: "Shashank Priyadarshi"},
 	// although m[1] will always return "Shashank Priyadarshi"
@@ -337,70 +423,82 @@ func custom() {
 
 	type Weekday string
 	var (
-		Sunday  Weekday
-		Monday  Weekday
-		Tuesday Weekday
+		Sunday  Weekday = "sunday"
+		Monday  Weekday = "monday"
+		Tuesday Weekday = "tuesday"
 	)
 
 	type DayOfMonth int
 	var (
-		First  DayOfMonth
-		Second DayOfMonth
+		First  DayOfMonth = 1
+		Second DayOfMonth = 2
 	)
+
+	// Variables must be used, constants and types do not have to be
+	fmt.Println("custom types: ", Mango, Banana, Apple, Sunday, Monday, Tuesday, First, Second)
+
+	// Named types keep values of different kinds apart, even when they have the same underlying type
+	// units.Meters and units.Seconds from the calculator are both float64, but cannot be mixed by mistake
+	distance := 5*units.Kilometer + 300*units.Meter // units.Meters
+	duration := 2 * units.Minute                     // units.Seconds
+	// distance + duration // Invalid code: mismatched types units.Meters and units.Seconds
+	fmt.Println("named types: ", distance, duration, units.Speed(distance, duration), "m/s")
 }
 
-func structs() {
-	// Object Oriented Programming: Classes and Objects
-	// Define classes, each class has some properties and methods
-	// Objects are instances of these classes
-{
-	type InteligenceLevel int8
-	const (
-		Poor InteligenceLevel = iota
-		Average
-		Good
-		Excellent
-	)
+// Object Oriented Programming: Classes and Objects
+// Define classes, each class has some properties and methods
+// Objects are instances of these classes
+// Methods can only be declared at the package level, not inside a function, so the classes of structs are declared here
 
-	type Animal struct {
-		// These are properties of the class Animal
-		Name             string
-		Species          string
-		InteligenceLevel InteligenceLevel // Poor, Average, Good, Excellent starting from 0
-		Age              int
-		Weight           int
-	}
+type InteligenceLevel int8
 
-	// Dog is a class which inherits from Animal
-	// However unline in other languages like Java, where there are keywords like implements, extends to enable Inheritance
-	// In Go there are no specific keywords to enable Inheritance
-	type Dog struct {
-		Animal Animal // Composition instead of Inheritance
-		Breed  string
-	}
+const (
+	Poor InteligenceLevel = iota
+	Average
+	Good
+	Excellent
+)
 
-	type Cat struct {
-		Animal Animal
-		Breed  string
-	}
+type Animal struct {
+	// These are properties of the class Animal
+	Name             string
+	Species          string
+	InteligenceLevel InteligenceLevel // Poor, Average, Good, Excellent starting from 0
+	Age              int
+	Weight           int
+}
 
-	// Speak is a method of the class Animal
-	// This is a generic speak method applicable for all animals
-	// It accepts speech string as argument
-	// This argument represents how different animals speak
-	func (a Animal) Speak(animal, speech string) {
-		fmt.Println(fmt.Sprintf("%s is %s", animal, speech))
-	}
+// Dog is a class which inherits from Animal
+// However unline in other languages like Java, where there are keywords like implements, extends to enable Inheritance
+// In Go there are no specific keywords to enable Inheritance
+type Dog struct {
+	Animal Animal // Composition instead of Inheritance
+	Breed  string
+}
 
-	func (d Dog) Speak() { // Receiver function or methods as they are called in Go
-		d.Animal.Speak("dog", "barking")
-	}
+type Cat struct {
+	Animal Animal
+	Breed  string
+}
 
-	func (c Cat) Speak() {
-		c.Animal.Speak("cat", "meowing")
-	}
+// Speak is a method of the class Animal
+// This is a generic speak method applicable for all animals
+// It accepts speech string as argument
+// This argument represents how different animals speak
+func (a Animal) Speak(animal, speech string) {
+	fmt.Printf("%s %s is %s\n", animal, a.Name, speech)
+}
+
+func (d Dog) Speak() { // Receiver function or methods as they are called in Go
+	d.Animal.Speak("dog", "barking")
+}
 
-	dog := Dog {
+func (c Cat) Speak() {
+	c.Animal.Speak("cat", "meowing")
+}
+
+func structs() {
+	dog := Dog{
 		Animal: Animal{
 			Name:             "Tommy",
 			Species:          "Dog",
@@ -413,62 +511,31 @@ func structs() {
 
 	dog.Speak()
 
-	// TODO: Create an employee class
-	// Create an Address class
-	// Employee will have name, age, level(enum), addresses
-	// Create UpdateAddress method for an employee whenever a new address is passed
-	// Create an employee object and invoke the UpdateAddress method
-}
-}
-
-type Level int8
-
-const (
-	Fresher Level = iota
-	Associate
-	Senior
-	Lead
-	Manager
-	Architect
-	VP
-	CEO
-)
-
-type Employee struct {
-	// A struct can have any number of properties
-	// Type of these properties can be any scalar, vector, custom or composite data type
-	// Properties of a struct are called fields
-	// Fields of a struct can be accessed using the dot operator
-
-	// For this employee class, if we create an instance: object
-	// For that object, the age, level, salary and address are subject to change
-	// This is called behaviour of the employee class
-	// Behaviour of a class is defined by methods
-	Name    string
-	Age     string
-	Level   Level
-	Salary  string
-	Address Address
-}
-
-func (e Employee) NotifyBirthday() {
-	// Start a timer at every birthday for next year's birthday
-	// Whenever the timer stops, send a "Happy Birthday" notification to the employee
-	// Start the timer again for next year
-}
-
-func (e Employee) UpdateAddress(street, city, state, pincode string) {
-	e.Address = Address{
-		Street:  street,
-		City:    city,
-		State:   state,
-		Pincode: pincode,
-	}
-}
-
-type Address struct {
-	Street  string
-	City    string
-	State   string
-	Pincode string
+	// Declaring the method inside structs would not compile: methods belong to a type, and are declared next to it
+	// func (c Cat) Purr() {} // Invalid code: syntax error, a function inside a function can only be a function literal
+
+	// rational.Rational from the calculator is a struct with methods too, it holds a fraction like 1/2
+	// Its fields are unexported, so New is the only way to create one, and New keeps it normalized: 2/4 becomes 1/2
+	half, _ := rational.New(2, 4)
+	third, _ := rational.Parse("1/3")
+	sum, _ := half.Add(third)
+	fmt.Println(half, "+", third, "=", sum) // 1/2 + 1/3 = 5/6, divide.Divide(1, 2) would give 0
+
+	// Employee has a name, a date of birth, a level(enum), a salary and addresses, see employee.go
+	// UpdateAddress is invoked whenever a new address is passed, the old one is kept in Previous
+	salary, _ := money.Parse("INR 1200000.00")
+	employee := Employee{Name: "Shashank", DateOfBirth: time.Date(1995, 8, 15, 0, 0, 0, 0, time.UTC), Level: Senior,
+		Salary: salary, Permanent: Address{City: "Patna", State: "Bihar"}}
+	_ = employee.UpdateAddress(Address{City: "Bengaluru", State: "Karnataka"}, time.Date(2020, 1, 1, 0, 0, 0, 0, time.UTC))
+	_ = employee.UpdateAddress(Address{City: "Hyderabad", State: "Telangana"}, time.Date(2023, 6, 1, 0, 0, 0, 0, time.UTC))
+	fmt.Println("current address: ", employee.Address.City, ", previous addresses: ", len(employee.Previous))
+	fmt.Println("level: ", employee.Level) // Prints Senior instead of 2, fmt calls the String method of Level
+
+	// The age is a method, not a field: it is computed from the date of birth, so it cannot be stored wrong or go stale
+	// The salary is a money.Money, employee.Salary = "12 lakh" does not compile, a string field would have taken it
+	fmt.Println("age on 1 Jan 2025: ", employee.Age(time.Date(2025, 1, 1, 0, 0, 0, 0, time.UTC)), ", salary: ", employee.Salary)
+
+	// WithAddress has a value receiver, it changes a copy and returns it, employee keeps its address
+	moved, _ := employee.WithAddress(Address{City: "Pune", State: "Maharashtra"}, time.Date(2024, 3, 1, 0, 0, 0, 0, time.UTC))
+	fmt.Println("copy moved to: ", moved.Address.City, ", employee stayed in: ", employee.Address.City)
 }